@@ -0,0 +1,252 @@
+//! Typed assessment results shared by every calculator.
+//!
+//! An [`Assessment`] carries the computed value together with its unit, the
+//! category it falls into, the reference range it was compared against, the
+//! formula used and the associated risk level. Turning it into text is the
+//! job of the [`report`](crate::report) module.
+
+use crate::bmi::standard::BmiReferenceStandard;
+use crate::whr::waist::WaistStandard;
+use crate::i18n::{self, Lang, Message};
+
+/// Health risk associated with a classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RiskLevel {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+}
+
+impl Classification for RiskLevel {
+    fn message(&self) -> Message {
+        Message::Risk(*self)
+    }
+
+    /// Used by metrics whose category is the risk itself (e.g. WHR).
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(*self)
+    }
+}
+
+/// Unit in which an assessed value is expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Unit {
+    KilogramsPerSquareMeter,
+    KilogramsPerCubicMeter,
+    KilocaloriesPerDay,
+    Kilograms,
+    Centimeters,
+    SquareMeters,
+    Percent,
+    Ratio,
+}
+
+impl Unit {
+    pub fn symbol(&self) -> &'static str {
+        i18n::text(Lang::EnUs, Message::Unit(*self))
+    }
+}
+
+/// Formula or method used to obtain an assessed value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Formula {
+    /// BMI = weight / height².
+    Quetelet,
+    /// Revised Harris-Benedict equation (Roza & Shizgal, 1984).
+    HarrisBenedict,
+    /// Mifflin-St Jeor TMB equation (1990).
+    MifflinStJeor,
+    /// Katch-McArdle lean-mass TMB equation.
+    KatchMcArdle,
+    /// Cunningham lean-mass TMB equation (1980).
+    Cunningham,
+    /// BMI divided by the upper limit of normal BMI.
+    BmiPrime,
+    /// Trefethen's new BMI: 1.3 × weight / height^2.5.
+    Trefethen,
+    /// Ponderal (Rohrer) Index: weight / height³.
+    Ponderal,
+    /// Deurenberg BMI-based body fat equation.
+    Deurenberg,
+    /// US Navy circumference body fat equation (Hodgdon & Beckett, 1984).
+    UsNavy,
+    /// Jackson-Pollock 3-site skinfold equation (1978/1980).
+    JacksonPollock3,
+    /// Jackson-Pollock 7-site skinfold equation (1978/1980).
+    JacksonPollock7,
+    /// Durnin-Womersley 4-site skinfold equation (1974).
+    DurninWomersley,
+    /// Parrillo 9-site skinfold method.
+    Parrillo,
+    /// CUN-BAE body fat estimator (Gómez-Ambrosi et al., 2012).
+    CunBae,
+    /// Relative Fat Mass (Woolcott & Bergman, 2018).
+    RelativeFatMass,
+    /// Boer lean body mass (1984).
+    Boer,
+    /// James lean body mass (1976).
+    James,
+    /// Hume lean body mass (1966).
+    Hume,
+    /// Mosteller body surface area (1987).
+    Mosteller,
+    /// DuBois & DuBois body surface area (1916).
+    DuBois,
+    /// Haycock body surface area (1978).
+    Haycock,
+    /// Gehan & George body surface area (1970).
+    GehanGeorge,
+    /// Boyd body surface area (1935).
+    Boyd,
+    /// Waist circumference divided by hip circumference.
+    WaistToHip,
+    /// Waist circumference divided by height.
+    WaistToHeight,
+    /// Waist circumference compared with absolute cut-offs.
+    WaistCircumference,
+    /// Devine ideal body weight (1974).
+    Devine,
+    /// Robinson ideal body weight (1983).
+    Robinson,
+    /// Miller ideal body weight (1983).
+    Miller,
+    /// Hamwi ideal body weight (1964).
+    Hamwi,
+}
+
+impl Formula {
+    pub fn name(&self) -> &'static str {
+        i18n::text(Lang::EnUs, Message::Formula(*self))
+    }
+}
+
+/// Published set of cut-offs a value was classified with, when more than one exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Standard {
+    Bmi(BmiReferenceStandard),
+    Waist(WaistStandard),
+}
+
+/// Healthy reference range a value was compared against.
+/// `min` is inclusive and `max` exclusive unless `max_inclusive` is set;
+/// `None` means unbounded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferenceRange {
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    /// The range includes `max`, for standards whose risk starts above the cut-off.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_inclusive: bool,
+}
+
+impl ReferenceRange {
+    pub fn new(min: Option<f32>, max: Option<f32>) -> Self {
+        ReferenceRange { min, max, max_inclusive: false }
+    }
+
+    /// The same range, including `max`.
+    pub fn with_inclusive_max(self) -> Self {
+        ReferenceRange { max_inclusive: true, ..self }
+    }
+
+    /// Returns true if `value` lies inside the range.
+    pub fn contains(&self, value: f32) -> bool {
+        self.min.is_none_or(|min| value >= min)
+            && self.max.is_none_or(|max| if self.max_inclusive { value <= max } else { value < max })
+    }
+}
+
+/// A category that knows its display label and associated risk.
+pub trait Classification {
+    /// Catalog key of the category's label.
+    fn message(&self) -> Message;
+
+    /// Label in English.
+    fn label(&self) -> &'static str {
+        self.label_in(Lang::EnUs)
+    }
+
+    /// Label in the given language.
+    fn label_in(&self, lang: Lang) -> &'static str {
+        i18n::text(lang, self.message())
+    }
+
+    /// Risk level implied by the category, if the metric expresses a risk.
+    fn risk(&self) -> Option<RiskLevel>;
+}
+
+/// Result of a health assessment.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Assessment<C> {
+    pub value: f32,
+    pub unit: Unit,
+    pub category: C,
+    pub reference_range: ReferenceRange,
+    pub formula: Formula,
+    pub risk: Option<RiskLevel>,
+    /// Cut-offs used for the category, for metrics with several standards.
+    pub standard: Option<Standard>,
+}
+
+impl<C: Classification> Assessment<C> {
+    /// Builds an assessment, deriving the risk level from the category.
+    pub fn new(
+        value: f32,
+        unit: Unit,
+        category: C,
+        reference_range: ReferenceRange,
+        formula: Formula,
+    ) -> Self {
+        let risk = category.risk();
+        Assessment { value, unit, category, reference_range, formula, risk, standard: None }
+    }
+
+    /// Records the standard whose cut-offs produced the category.
+    pub fn with_standard(mut self, standard: Standard) -> Self {
+        self.standard = Some(standard);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_range_contains() {
+        let range = ReferenceRange::new(Some(18.5), Some(25.0));
+        assert!(range.contains(18.5));
+        assert!(range.contains(24.9));
+        assert!(!range.contains(25.0));
+        assert!(ReferenceRange::new(None, Some(0.9)).contains(0.5));
+        assert!(ReferenceRange::new(None, Some(0.9)).with_inclusive_max().contains(0.9));
+    }
+
+    #[test]
+    fn test_risk_level_ordering() {
+        assert!(RiskLevel::Low < RiskLevel::Moderate);
+        assert!(RiskLevel::High < RiskLevel::VeryHigh);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_serde_round_trip() {
+        let assessment = Assessment::new(
+            0.95,
+            Unit::Ratio,
+            RiskLevel::High,
+            ReferenceRange::new(None, Some(0.9)),
+            Formula::WaistToHip,
+        );
+        let json = serde_json::to_string(&assessment).unwrap();
+        let back: Assessment<RiskLevel> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, assessment);
+    }
+}
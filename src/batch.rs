@@ -0,0 +1,291 @@
+//! Batch processing of many people at once from a CSV file.
+//!
+//! Rows are read and written one at a time, so the input never has to fit in
+//! memory. Each row is run through the BMI, TMB, PGC and WHR calculators;
+//! invalid rows are reported in an `error` column instead of stopping the run.
+
+use std::io::{self, BufRead, Write};
+
+use crate::assessment::Classification;
+use crate::bmi::calculator::{BmiCalculator, BmiData, BmiForAge};
+use crate::body_fat::{BodyFatCalculator, BodyFatData};
+use crate::common::sex::Sex;
+use crate::i18n::{self, Lang};
+use crate::metabolism::tmb::{TmbCalculator, TmbData};
+use crate::parse::{parse_age, parse_circumference, parse_height, parse_weight};
+use crate::whr::calculator::{WhrCalculator, WhrCalculatorTrait, WhrData};
+
+/// Columns every input file must have (waist and hip are optional).
+const REQUIRED_COLUMNS: [&str; 4] = ["weight", "height", "age", "sex"];
+
+/// Columns of the output file, in order.
+pub const OUTPUT_COLUMNS: [&str; 17] = [
+    "row",
+    "weight",
+    "height",
+    "age",
+    "sex",
+    "waist",
+    "hip",
+    "bmi",
+    "bmi_category",
+    "tmb",
+    "tmb_category",
+    "pgc",
+    "pgc_sex_category",
+    "pgc_age_category",
+    "whr",
+    "whr_risk",
+    "error",
+];
+
+/// Totals of a batch run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BatchSummary {
+    pub rows: usize,
+    pub rows_with_errors: usize,
+}
+
+/// Position of each known column in the input header.
+struct Columns {
+    weight: usize,
+    height: usize,
+    age: usize,
+    sex: usize,
+    waist: Option<usize>,
+    hip: Option<usize>,
+}
+
+impl Columns {
+    fn from_header(header: &[String]) -> io::Result<Self> {
+        let find = |name: &str| header.iter().position(|h| h.trim().eq_ignore_ascii_case(name));
+        if let Some(missing) = REQUIRED_COLUMNS.iter().find(|name| find(name).is_none()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("input CSV has no '{}' column", missing),
+            ));
+        }
+        Ok(Columns {
+            weight: find("weight").unwrap_or_default(),
+            height: find("height").unwrap_or_default(),
+            age: find("age").unwrap_or_default(),
+            sex: find("sex").unwrap_or_default(),
+            waist: find("waist"),
+            hip: find("hip"),
+        })
+    }
+}
+
+/// Reads people from `reader` and writes one result row per person to `writer`.
+///
+/// The input needs a header with `weight` (kg), `height` (m), `age`, `sex` and,
+/// optionally, `waist` and `hip` (cm). Fields may be separated by `,` or `;`
+/// (detected from the header); numbers may use comma decimals and unit
+/// suffixes, as accepted by [`crate::parse`]. The output is always comma-separated, with
+/// category labels and error messages in `lang`.
+pub fn process_csv<R: BufRead, W: Write>(reader: R, mut writer: W, lang: Lang) -> io::Result<BatchSummary> {
+    let mut lines = reader.lines();
+    let header_line = match lines.next() {
+        Some(line) => line?,
+        None => return Err(io::Error::new(io::ErrorKind::InvalidData, "input CSV is empty")),
+    };
+    let delimiter = if header_line.contains(';') { ';' } else { ',' };
+    let columns = Columns::from_header(&split_csv_line(&header_line, delimiter))?;
+
+    writeln!(writer, "{}", OUTPUT_COLUMNS.join(","))?;
+    let mut summary = BatchSummary::default();
+    for line in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        summary.rows += 1;
+        let fields = split_csv_line(&line, delimiter);
+        let row = process_row(summary.rows, &fields, &columns, lang);
+        if !row.last().is_some_and(String::is_empty) {
+            summary.rows_with_errors += 1;
+        }
+        let escaped: Vec<String> = row.iter().map(|field| escape_csv_field(field)).collect();
+        writeln!(writer, "{}", escaped.join(","))?;
+    }
+    writer.flush()?;
+    Ok(summary)
+}
+
+/// Runs every calculator for one row; returns the output fields.
+fn process_row(number: usize, fields: &[String], columns: &Columns, lang: Lang) -> Vec<String> {
+    let field = |index: usize| fields.get(index).map(|f| f.trim()).unwrap_or("");
+    let optional = |index: Option<usize>| index.map(field).unwrap_or("");
+
+    let mut out = vec![String::new(); OUTPUT_COLUMNS.len()];
+    out[0] = number.to_string();
+    out[1] = field(columns.weight).to_string();
+    out[2] = field(columns.height).to_string();
+    out[3] = field(columns.age).to_string();
+    out[4] = field(columns.sex).to_string();
+    out[5] = optional(columns.waist).to_string();
+    out[6] = optional(columns.hip).to_string();
+
+    let mut errors: Vec<String> = Vec::new();
+    let person = parse_weight(&out[1]).and_then(|weight| {
+        Ok((
+            weight,
+            parse_height(&out[2])?,
+            parse_age(&out[3])?,
+            out[4].parse::<Sex>()?,
+        ))
+    });
+
+    match person {
+        Ok((weight, height, age, sex)) => {
+            // Under 20 the category comes from the BMI-for-age reference
+            match BmiData::new(weight, height).and_then(|data| BmiCalculator::assess_for_age(&data, age, sex)) {
+                Ok(BmiForAge::Adult(bmi)) => {
+                    out[7] = format!("{:.2}", bmi.value);
+                    out[8] = bmi.category.label_in(lang).to_string();
+                }
+                Ok(BmiForAge::Pediatric(bmi)) => {
+                    out[7] = format!("{:.2}", bmi.bmi);
+                    out[8] = bmi.category.label_in(lang).to_string();
+                }
+                Err(err) => errors.push(i18n::describe_error(lang, &err)),
+            }
+            match TmbData::new(weight, height, age, sex).and_then(|data| TmbCalculator::assess(&data)) {
+                Ok(tmb) => {
+                    out[9] = format!("{:.2}", tmb.value);
+                    out[10] = tmb.category.label_in(lang).to_string();
+                }
+                Err(err) => errors.push(i18n::describe_error(lang, &err)),
+            }
+            match BodyFatData::new(weight, height, age, sex).and_then(|data| BodyFatCalculator::assess(&data)) {
+                Ok(pgc) => {
+                    out[11] = format!("{:.2}", pgc.value);
+                    out[12] = pgc.category.by_sex.label_in(lang).to_string();
+                    out[13] = pgc.category.by_age.map(|by_age| by_age.label_in(lang).to_string()).unwrap_or_default();
+                }
+                Err(err) => errors.push(i18n::describe_error(lang, &err)),
+            }
+            if !out[5].is_empty() || !out[6].is_empty() {
+                let whr = parse_circumference("waist", &out[5])
+                    .and_then(|waist| WhrData::new(waist, parse_circumference("hip", &out[6])?, sex))
+                    .and_then(|data| WhrCalculator::assess(&data));
+                match whr {
+                    Ok(whr) => {
+                        out[14] = format!("{:.2}", whr.value);
+                        out[15] = whr.category.label_in(lang).to_string();
+                    }
+                    Err(err) => errors.push(i18n::describe_error(lang, &err)),
+                }
+            }
+        }
+        Err(err) => errors.push(i18n::describe_error(lang, &err)),
+    }
+
+    errors.dedup();
+    out[16] = errors.join("; ");
+    out
+}
+
+/// Splits one CSV line, honouring double-quoted fields (`""` is an escaped quote).
+pub fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.trim_end_matches(['\r', '\n']).chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+/// Quotes a field if it contains a comma, quote or line break.
+pub fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input: &str) -> (BatchSummary, Vec<String>) {
+        let mut output = Vec::new();
+        let summary = process_csv(input.as_bytes(), &mut output, Lang::EnUs).unwrap();
+        let lines = String::from_utf8(output).unwrap().lines().map(String::from).collect();
+        (summary, lines)
+    }
+
+    #[test]
+    fn test_process_valid_rows() {
+        let (summary, lines) = run("weight,height,age,sex,waist,hip\n70,1.75,30,M,90,100\n60,1.65,30,F,,\n");
+        assert_eq!(summary, BatchSummary { rows: 2, rows_with_errors: 0 });
+        assert_eq!(lines[0], OUTPUT_COLUMNS.join(","));
+        let first = split_csv_line(&lines[1], ',');
+        assert_eq!(first[7], "22.86");
+        assert_eq!(first[8], "Normal weight");
+        assert_eq!(first[14], "0.90");
+        assert_eq!(first[15], "Low");
+        assert_eq!(first[16], "");
+        let second = split_csv_line(&lines[2], ',');
+        assert_eq!(second[14], "");
+    }
+
+    #[test]
+    fn test_row_errors_do_not_stop_the_run() {
+        let (summary, lines) = run("sex;age;height;weight\nM;30;0;70\nX;30;1.75;70\nF;30;1.65;60\n");
+        assert_eq!(summary, BatchSummary { rows: 3, rows_with_errors: 2 });
+        assert!(lines[1].ends_with("height must be greater than zero"));
+        assert!(lines[2].ends_with("'X' is not a valid sex"));
+        assert!(lines[3].ends_with(','));
+    }
+
+    #[test]
+    fn test_portuguese_labels_and_errors() {
+        let mut output = Vec::new();
+        process_csv("weight,height,age,sex\n70,1.75,30,M\n70,0,30,M\n".as_bytes(), &mut output, Lang::PtBr).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        assert!(text.contains(",Peso normal,"));
+        assert!(text.contains("altura deve ser maior que zero"));
+    }
+
+    #[test]
+    fn test_comma_decimals_and_units() {
+        let (summary, lines) = run("weight;height;age;sex;waist;hip\n70,0;175cm;30;M;90;100\n154,3lb;1,75;30;M;;\n");
+        assert_eq!(summary, BatchSummary { rows: 2, rows_with_errors: 0 });
+        assert_eq!(split_csv_line(&lines[1], ',')[7], "22.86");
+        assert_eq!(split_csv_line(&lines[2], ',')[7], "22.85");
+    }
+
+    #[test]
+    fn test_children_use_bmi_for_age() {
+        let (_, lines) = run("weight,height,age,sex\n47,1.40,10,M\n");
+        let row = split_csv_line(&lines[1], ',');
+        assert_eq!(row[7], "23.98");
+        assert_eq!(row[8], "Obesity");
+    }
+
+    #[test]
+    fn test_missing_required_column() {
+        let result = process_csv("weight,height,sex\n".as_bytes(), Vec::new(), Lang::EnUs);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_split_and_escape() {
+        assert_eq!(split_csv_line("a,\"b,c\",\"d\"\"e\"", ','), vec!["a", "b,c", "d\"e"]);
+        assert_eq!(escape_csv_field("a, b"), "\"a, b\"");
+        assert_eq!(escape_csv_field("plain"), "plain");
+    }
+}
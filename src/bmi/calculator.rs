@@ -1,14 +1,47 @@
-/// This module provides functionality for calculating the Body Mass Index (BMI)
-/// and evaluating the corresponding health classification based on standard ranges.
+//! This module provides functionality for calculating the Body Mass Index (BMI)
+//! and evaluating the corresponding health classification based on standard ranges.
+
+use std::str::FromStr;
+
+use crate::assessment::{Assessment, Classification, Formula, ReferenceRange, RiskLevel, Standard, Unit};
+use crate::bmi::pediatric::{
+    GrowthReference, PediatricBmiCalculator, PediatricBmiData, PediatricBmiResult, ADULT_AGE_YEARS,
+};
+use crate::bmi::standard::BmiReferenceStandard;
+use crate::common::sex::Sex;
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::units::{Kilograms, Meters};
 
 /// Struct representing a person with weight (kg) and height (m).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BmiData {
     pub weight: f32,
     pub height: f32,
 }
 
+impl BmiData {
+    /// Creates validated BMI input, rejecting non-finite, non-positive or implausible values.
+    pub fn new(weight: impl Into<Kilograms>, height: impl Into<Meters>) -> Result<Self, HealthError> {
+        let data = BmiData {
+            weight: weight.into().0,
+            height: height.into().0,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that the weight (kg) and height (m) are plausible measurements.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("weight", self.weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        Ok(())
+    }
+}
+
 /// Enum representing the different categories of BMI classification.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BmiCategory {
     Underweight,
     NormalWeight,
@@ -18,6 +51,59 @@ pub enum BmiCategory {
     ObesityGrade3,
 }
 
+impl Classification for BmiCategory {
+    fn message(&self) -> Message {
+        Message::Bmi(*self)
+    }
+
+    /// Risk of comorbidities according to the WHO classification.
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            BmiCategory::Underweight => RiskLevel::Moderate,
+            BmiCategory::NormalWeight => RiskLevel::Low,
+            BmiCategory::Overweight => RiskLevel::Moderate,
+            BmiCategory::ObesityGrade1 => RiskLevel::High,
+            BmiCategory::ObesityGrade2 | BmiCategory::ObesityGrade3 => RiskLevel::VeryHigh,
+        })
+    }
+}
+
+impl FromStr for BmiCategory {
+    type Err = HealthError;
+
+    /// Parses names such as `normal`, `overweight` or `obesity-1`, in English or Portuguese.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "underweight" | "abaixodopeso" => Ok(BmiCategory::Underweight),
+            "normal" | "normalweight" | "pesonormal" => Ok(BmiCategory::NormalWeight),
+            "overweight" | "sobrepeso" => Ok(BmiCategory::Overweight),
+            "obesity1" | "obesitygrade1" | "obesidade1" | "obesidadegrau1" => Ok(BmiCategory::ObesityGrade1),
+            "obesity2" | "obesitygrade2" | "obesidade2" | "obesidadegrau2" => Ok(BmiCategory::ObesityGrade2),
+            "obesity3" | "obesitygrade3" | "obesidade3" | "obesidadegrau3" => Ok(BmiCategory::ObesityGrade3),
+            _ => Err(HealthError::Unrecognized {
+                field: "bmi category",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Typed result of a BMI assessment.
+pub type BmiAssessment = Assessment<BmiCategory>;
+
+/// BMI assessment with adult cut-offs, or BMI-for-age under 20.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum BmiForAge {
+    Adult(BmiAssessment),
+    Pediatric(PediatricBmiResult),
+}
+
+/// Healthy (normal weight) BMI range, in kg/m².
+pub const NORMAL_BMI_RANGE: ReferenceRange = ReferenceRange { min: Some(18.5), max: Some(25.0), max_inclusive: false };
+
 /// This struct adheres to the Single Responsibility Principle (SRP),
 /// being solely responsible for BMI calculation and classification.
 pub struct BmiCalculator;
@@ -30,30 +116,83 @@ impl BmiCalculator {
         data.weight / (data.height * data.height)
     }
 
+    /// Validates the input and calculates the BMI, never returning NaN or infinity.
+    pub fn try_calculate(data: &BmiData) -> Result<f32, HealthError> {
+        data.validate()?;
+        error::ensure_finite("bmi", Self::calculate(data))
+    }
+
     /// Returns the BMI classification category according to the calculated BMI.
     pub fn classify(bmi: f32) -> BmiCategory {
+        Self::classify_with_standard(bmi, BmiReferenceStandard::WhoInternational)
+    }
+
+    /// Classifies the BMI with the cut-offs of the chosen standard.
+    pub fn classify_with_standard(bmi: f32, standard: BmiReferenceStandard) -> BmiCategory {
+        let cut_offs = standard.cut_offs();
+        let at_least = |cut_off: Option<f32>| cut_off.is_some_and(|c| bmi >= c);
         match bmi {
-            b if b < 18.5 => BmiCategory::Underweight,
-            b if b < 25.0 => BmiCategory::NormalWeight,
-            b if b < 30.0 => BmiCategory::Overweight,
-            b if b < 35.0 => BmiCategory::ObesityGrade1,
-            b if b < 40.0 => BmiCategory::ObesityGrade2,
-            _ => BmiCategory::ObesityGrade3,
+            _ if at_least(cut_offs.obesity_grade3) => BmiCategory::ObesityGrade3,
+            b if b >= cut_offs.obesity_grade2 => BmiCategory::ObesityGrade2,
+            b if b >= cut_offs.obesity_grade1 => BmiCategory::ObesityGrade1,
+            _ if at_least(cut_offs.overweight) => BmiCategory::Overweight,
+            b if b >= cut_offs.normal => BmiCategory::NormalWeight,
+            _ => BmiCategory::Underweight,
         }
     }
+
+    /// Validates the input, calculates and classifies the BMI as a typed result.
+    pub fn assess(data: &BmiData) -> Result<BmiAssessment, HealthError> {
+        let bmi = Self::try_calculate(data)?;
+        Ok(Assessment::new(
+            bmi,
+            Unit::KilogramsPerSquareMeter,
+            Self::classify(bmi),
+            NORMAL_BMI_RANGE,
+            Formula::Quetelet,
+        ))
+    }
+
+    /// Like [`assess`](Self::assess), with the cut-offs of the chosen standard
+    /// recorded in the result.
+    pub fn assess_with_standard(data: &BmiData, standard: BmiReferenceStandard) -> Result<BmiAssessment, HealthError> {
+        let bmi = Self::try_calculate(data)?;
+        Ok(Assessment::new(
+            bmi,
+            Unit::KilogramsPerSquareMeter,
+            Self::classify_with_standard(bmi, standard),
+            standard.normal_range(),
+            Formula::Quetelet,
+        )
+        .with_standard(Standard::Bmi(standard)))
+    }
+
+    /// Assesses the BMI with the adult cut-offs from age 20 and with the WHO
+    /// BMI-for-age reference below it (age taken as whole years).
+    pub fn assess_for_age(data: &BmiData, age: u32, sex: Sex) -> Result<BmiForAge, HealthError> {
+        Self::assess_for_age_with(data, age * 12, sex, GrowthReference::Who)
+    }
+
+    /// Like [`assess_for_age`](Self::assess_for_age), with the age in months and a chosen reference.
+    pub fn assess_for_age_with(
+        data: &BmiData,
+        age_months: u32,
+        sex: Sex,
+        reference: GrowthReference,
+    ) -> Result<BmiForAge, HealthError> {
+        if age_months >= ADULT_AGE_YEARS * 12 {
+            error::validate_age(age_months / 12)?;
+            return Ok(BmiForAge::Adult(Self::assess(data)?));
+        }
+        let child = PediatricBmiData::new(data.weight, data.height, age_months, sex)?;
+        Ok(BmiForAge::Pediatric(PediatricBmiCalculator::assess(&child, reference)?))
+    }
+
     /// Returns a formatted human-readable string for the result.
     #[allow(unused_variables)]
     pub fn evaluation_result(bmi: f32, category: &BmiCategory) -> String {
-        let classification = match category {
-            BmiCategory::Underweight => "Underweight",
-            BmiCategory::NormalWeight => "Normal weight",
-            BmiCategory::Overweight => "Overweight",
-            BmiCategory::ObesityGrade1 => "Obesity Grade 1",
-            BmiCategory::ObesityGrade2 => "Obesity Grade 2",
-            BmiCategory::ObesityGrade3 => "Obesity Grade 3 (morbid)",
-        };
         // Return a formatted string with the BMI classification
-        format!("Your BMI assessment is:: {}", classification)
+        format!("Your BMI assessment is:: {}", category.label())
     }
 }
 
@@ -61,6 +200,47 @@ impl BmiCalculator {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_assess_for_age_switches_at_20() {
+        let data = BmiData::new(70.0, 1.75).unwrap();
+        assert!(matches!(BmiCalculator::assess_for_age(&data, 20, Sex::Male), Ok(BmiForAge::Adult(_))));
+        match BmiCalculator::assess_for_age(&data, 12, Sex::Male).unwrap() {
+            BmiForAge::Pediatric(result) => assert_eq!(result.age_months, 144),
+            other => panic!("expected a pediatric result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_with_asian_standards() {
+        use BmiReferenceStandard::*;
+        assert_eq!(BmiCalculator::classify_with_standard(24.0, WhoInternational), BmiCategory::NormalWeight);
+        assert_eq!(BmiCalculator::classify_with_standard(24.0, WhoAsianPacific), BmiCategory::Overweight);
+        assert_eq!(BmiCalculator::classify_with_standard(26.0, WhoAsianPacific), BmiCategory::ObesityGrade1);
+        assert_eq!(BmiCalculator::classify_with_standard(28.0, WhoExpertConsultation), BmiCategory::ObesityGrade1);
+        assert_eq!(BmiCalculator::classify_with_standard(38.0, WhoExpertConsultation), BmiCategory::ObesityGrade3);
+        assert_eq!(BmiCalculator::classify_with_standard(45.0, WhoAsianPacific), BmiCategory::ObesityGrade2);
+        assert_eq!(BmiCalculator::classify_with_standard(26.0, Jasso), BmiCategory::ObesityGrade1);
+        assert_eq!(BmiCalculator::classify_with_standard(17.0, Jasso), BmiCategory::Underweight);
+    }
+
+    #[test]
+    fn test_assess_records_standard() {
+        let data = BmiData::new(70.0, 1.70).unwrap();
+        let assessment = BmiCalculator::assess_with_standard(&data, BmiReferenceStandard::WhoExpertConsultation).unwrap();
+        assert_eq!(assessment.category, BmiCategory::Overweight);
+        assert_eq!(assessment.reference_range, ReferenceRange::new(Some(18.5), Some(23.0)));
+        assert_eq!(assessment.standard, Some(Standard::Bmi(BmiReferenceStandard::WhoExpertConsultation)));
+        assert_eq!(BmiCalculator::assess(&data).unwrap().standard, None);
+    }
+
+    #[test]
+    fn test_parse_category() {
+        assert_eq!("normal".parse::<BmiCategory>(), Ok(BmiCategory::NormalWeight));
+        assert_eq!("Obesity-1".parse::<BmiCategory>(), Ok(BmiCategory::ObesityGrade1));
+        assert_eq!("sobrepeso".parse::<BmiCategory>(), Ok(BmiCategory::Overweight));
+        assert!("thin".parse::<BmiCategory>().is_err());
+    }
+
     #[test]
     fn test_calculate_bmi() {
         let data = BmiData { weight: 70.0, height: 1.75 };
@@ -111,4 +291,46 @@ mod tests {
         let result = BmiCalculator::evaluation_result(bmi, &category);
         assert_eq!(result, "Your BMI assessment is:: Normal weight");
     }
+
+    #[test]
+    fn test_assess() {
+        let data = BmiData::new(70.0, 1.75).unwrap();
+        let assessment = BmiCalculator::assess(&data).unwrap();
+        assert!((assessment.value - 22.86).abs() < 0.01);
+        assert_eq!(assessment.unit, Unit::KilogramsPerSquareMeter);
+        assert_eq!(assessment.category, BmiCategory::NormalWeight);
+        assert_eq!(assessment.reference_range, NORMAL_BMI_RANGE);
+        assert_eq!(assessment.formula, Formula::Quetelet);
+        assert_eq!(assessment.risk, Some(RiskLevel::Low));
+    }
+
+    #[test]
+    fn test_new_rejects_zero_height() {
+        let result = BmiData::new(70.0, 0.0);
+        assert_eq!(result.err(), Some(HealthError::Zero { field: "height" }));
+    }
+
+    #[test]
+    fn test_try_calculate_rejects_invalid_data() {
+        let data = BmiData { weight: f32::NAN, height: 1.75 };
+        let result = BmiCalculator::try_calculate(&data);
+        assert_eq!(result, Err(HealthError::NotFinite { field: "weight" }));
+    }
+
+    #[test]
+    fn test_try_calculate_valid_data() {
+        let data = BmiData::new(70.0, 1.75).unwrap();
+        let bmi = BmiCalculator::try_calculate(&data).unwrap();
+        assert!((bmi - 22.86).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_new_with_imperial_units() {
+        use crate::units::{FeetInches, Pounds};
+        let imperial = BmiData::new(Pounds(160.0), FeetInches::new(5, 10.0)).unwrap();
+        let metric = BmiData::new(72.5748, 1.778).unwrap();
+        let bmi = BmiCalculator::calculate(&imperial);
+        assert!((bmi - BmiCalculator::calculate(&metric)).abs() < 1e-3);
+        assert!((bmi - 22.96).abs() < 0.01);
+    }
 }
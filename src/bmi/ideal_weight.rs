@@ -0,0 +1,216 @@
+//! "How much should I weigh?": healthy weight range and ideal body weight.
+//!
+//! The healthy range is the normal-BMI band applied to the person's height.
+//! Ideal body weight (IBW) formulas add a fixed amount per inch over 5 feet;
+//! adjusted body weight is used for drug dosing and nutrition in obesity.
+
+use crate::assessment::Formula;
+use crate::bmi::standard::BmiReferenceStandard;
+use crate::common::sex::Sex;
+use crate::error::{self, HealthError};
+use crate::units::{Inches, Kilograms, Meters};
+
+/// Height at which the IBW formulas start, in inches (5 feet).
+const BASE_HEIGHT_INCHES: f32 = 60.0;
+/// Share of the excess weight counted as metabolically active tissue.
+const ADJUSTED_WEIGHT_FACTOR: f32 = 0.4;
+/// Weight above which (as a share of IBW) the adjusted body weight applies.
+const OBESE_IBW_RATIO: f32 = 1.2;
+
+/// Ideal body weight equation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdealWeightFormula {
+    /// Devine (1974): 50 / 45.5 kg + 2.3 kg per inch.
+    Devine,
+    /// Robinson (1983): 52 / 49 kg + 1.9 / 1.7 kg per inch.
+    Robinson,
+    /// Miller (1983): 56.2 / 53.1 kg + 1.41 / 1.36 kg per inch.
+    Miller,
+    /// Hamwi (1964): 48 / 45.5 kg + 2.7 / 2.2 kg per inch.
+    Hamwi,
+}
+
+impl IdealWeightFormula {
+    /// Every formula, in publication order of common use.
+    pub const ALL: [IdealWeightFormula; 4] = [
+        IdealWeightFormula::Devine,
+        IdealWeightFormula::Robinson,
+        IdealWeightFormula::Miller,
+        IdealWeightFormula::Hamwi,
+    ];
+
+    /// Formula reported in results.
+    pub fn formula(&self) -> Formula {
+        match self {
+            IdealWeightFormula::Devine => Formula::Devine,
+            IdealWeightFormula::Robinson => Formula::Robinson,
+            IdealWeightFormula::Miller => Formula::Miller,
+            IdealWeightFormula::Hamwi => Formula::Hamwi,
+        }
+    }
+
+    /// Base weight at 5 feet (kg) and kg added per inch above it.
+    fn coefficients(&self, sex: Sex) -> (f32, f32) {
+        match (self, sex) {
+            (IdealWeightFormula::Devine, Sex::Male) => (50.0, 2.3),
+            (IdealWeightFormula::Devine, Sex::Female) => (45.5, 2.3),
+            (IdealWeightFormula::Robinson, Sex::Male) => (52.0, 1.9),
+            (IdealWeightFormula::Robinson, Sex::Female) => (49.0, 1.7),
+            (IdealWeightFormula::Miller, Sex::Male) => (56.2, 1.41),
+            (IdealWeightFormula::Miller, Sex::Female) => (53.1, 1.36),
+            (IdealWeightFormula::Hamwi, Sex::Male) => (48.0, 2.7),
+            (IdealWeightFormula::Hamwi, Sex::Female) => (45.5, 2.2),
+        }
+    }
+}
+
+/// Range of body weights, in kg.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Ideal body weight by one formula, in kg.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdealWeight {
+    pub formula: Formula,
+    pub weight: f32,
+}
+
+/// Weight targets for a person of a given height.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightTargets {
+    /// Weights inside the normal-BMI band.
+    pub healthy_range: WeightRange,
+    /// IBW by every formula that gives a plausible weight; empty when the sex
+    /// is unknown or the height is under 5 feet, where the formulas are undefined.
+    pub ideal: Vec<IdealWeight>,
+    /// Adjusted body weight (Devine IBW), only when weight exceeds 120% of IBW.
+    pub adjusted: Option<f32>,
+}
+
+/// Calculator for weight targets; builds on the BMI cut-offs.
+pub struct IdealWeightCalculator;
+
+impl IdealWeightCalculator {
+    /// Weights whose BMI falls in the normal band of the standard.
+    pub fn healthy_weight_range(height: impl Into<Meters>, standard: BmiReferenceStandard) -> Result<WeightRange, HealthError> {
+        let height = error::validate_measurement("height", height.into().0, error::HEIGHT_RANGE_M)?;
+        let range = standard.normal_range();
+        let squared = height * height;
+        Ok(WeightRange {
+            min: range.min.unwrap_or_default() * squared,
+            max: range.max.unwrap_or_default() * squared,
+        })
+    }
+
+    /// Ideal body weight in kg by the chosen formula.
+    pub fn ideal_weight(height: impl Into<Meters>, sex: Sex, formula: IdealWeightFormula) -> f32 {
+        let inches = Inches::from(height.into()).0;
+        let (base, per_inch) = formula.coefficients(sex);
+        base + per_inch * (inches - BASE_HEIGHT_INCHES)
+    }
+
+    /// Validates the height and returns a positive ideal body weight.
+    pub fn try_ideal_weight(height: impl Into<Meters>, sex: Sex, formula: IdealWeightFormula) -> Result<f32, HealthError> {
+        let height = error::validate_measurement("height", height.into().0, error::HEIGHT_RANGE_M)?;
+        let ideal = Self::ideal_weight(Meters(height), sex, formula);
+        // Very short statures extrapolate below zero
+        error::validate_measurement("ideal weight", ideal, error::WEIGHT_RANGE_KG)
+    }
+
+    /// Adjusted body weight: IBW plus 40% of the weight above it.
+    pub fn adjusted_body_weight(weight: impl Into<Kilograms>, ideal: f32) -> f32 {
+        ideal + ADJUSTED_WEIGHT_FACTOR * (weight.into().0 - ideal)
+    }
+
+    /// Healthy range, IBW by every formula (when the sex is known and the height
+    /// is at least 5 feet) and, for people above 120% of their Devine IBW, the
+    /// adjusted body weight.
+    pub fn targets(
+        weight: impl Into<Kilograms>,
+        height: impl Into<Meters>,
+        sex: Option<Sex>,
+        standard: BmiReferenceStandard,
+    ) -> Result<WeightTargets, HealthError> {
+        let weight = error::validate_measurement("weight", weight.into().0, error::WEIGHT_RANGE_KG)?;
+        let height = height.into();
+        let healthy_range = Self::healthy_weight_range(height, standard)?;
+        let (ideal, adjusted) = match sex {
+            Some(sex) if Inches::from(height).0 >= BASE_HEIGHT_INCHES => {
+                let ideal: Vec<IdealWeight> = IdealWeightFormula::ALL
+                    .iter()
+                    .filter_map(|formula| {
+                        let weight = Self::try_ideal_weight(height, sex, *formula).ok()?;
+                        Some(IdealWeight { formula: formula.formula(), weight })
+                    })
+                    .collect();
+                let devine = ideal.iter().find(|ideal| ideal.formula == Formula::Devine).map(|ideal| ideal.weight);
+                let adjusted = devine
+                    .filter(|devine| weight > OBESE_IBW_RATIO * devine)
+                    .map(|devine| Self::adjusted_body_weight(weight, devine));
+                (ideal, adjusted)
+            }
+            _ => (Vec::new(), None),
+        };
+        Ok(WeightTargets { healthy_range, ideal, adjusted })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_healthy_weight_range() {
+        let range = IdealWeightCalculator::healthy_weight_range(1.75, BmiReferenceStandard::WhoInternational).unwrap();
+        assert!((range.min - 56.66).abs() < 0.01);
+        assert!((range.max - 76.56).abs() < 0.01);
+        let asian = IdealWeightCalculator::healthy_weight_range(1.75, BmiReferenceStandard::WhoAsianPacific).unwrap();
+        assert!((asian.max - 70.44).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_ideal_weight_formulas() {
+        let ibw = |sex, formula| IdealWeightCalculator::ideal_weight(1.75, sex, formula);
+        assert!((ibw(Sex::Male, IdealWeightFormula::Devine) - 70.46).abs() < 0.01);
+        assert!((ibw(Sex::Male, IdealWeightFormula::Robinson) - 68.91).abs() < 0.01);
+        assert!((ibw(Sex::Male, IdealWeightFormula::Miller) - 68.75).abs() < 0.01);
+        assert!((ibw(Sex::Male, IdealWeightFormula::Hamwi) - 72.02).abs() < 0.01);
+        assert!((ibw(Sex::Female, IdealWeightFormula::Devine) - 65.96).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_adjusted_weight_only_when_obese() {
+        let obese = IdealWeightCalculator::targets(110.0, 1.75, Some(Sex::Male), BmiReferenceStandard::WhoInternational).unwrap();
+        // 70.46 + 0.4 × (110 − 70.46) = 86.28
+        assert!((obese.adjusted.unwrap() - 86.28).abs() < 0.01);
+        let normal = IdealWeightCalculator::targets(70.0, 1.75, Some(Sex::Male), BmiReferenceStandard::WhoInternational).unwrap();
+        assert_eq!(normal.adjusted, None);
+        assert_eq!(normal.ideal.len(), 4);
+    }
+
+    #[test]
+    fn test_targets_without_sex() {
+        let targets = IdealWeightCalculator::targets(70.0, 1.75, None, BmiReferenceStandard::WhoInternational).unwrap();
+        assert!(targets.ideal.is_empty());
+    }
+
+    #[test]
+    fn test_targets_below_five_feet_leave_out_ideal_weight() {
+        let targets = IdealWeightCalculator::targets(30.0, 0.95, Some(Sex::Female), BmiReferenceStandard::WhoInternational).unwrap();
+        assert!(targets.ideal.is_empty());
+        assert_eq!(targets.adjusted, None);
+    }
+
+    #[test]
+    fn test_rejects_ideal_weight_below_plausible() {
+        let result = IdealWeightCalculator::try_ideal_weight(0.5, Sex::Female, IdealWeightFormula::Devine);
+        assert!(matches!(result, Err(HealthError::Negative { field: "ideal weight", .. })));
+    }
+}
@@ -0,0 +1,233 @@
+//! Alternatives to the classic BMI for very tall or very short people.
+//!
+//! BMI Prime expresses the BMI as a ratio to the upper limit of normal.
+//! Trefethen's "new BMI" scales with height^2.5, which matches how weight
+//! grows with height better than the square. The Ponderal (Rohrer) Index
+//! divides by the cube of the height and stays nearly constant with stature.
+
+use std::str::FromStr;
+
+use crate::assessment::{Assessment, Classification, Formula, ReferenceRange, RiskLevel, Standard, Unit};
+use crate::bmi::calculator::{BmiAssessment, BmiCalculator, BmiData};
+use crate::bmi::standard::BmiReferenceStandard;
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+
+/// Healthy Ponderal Index range for adults, in kg/m³.
+pub const NORMAL_PONDERAL_RANGE: ReferenceRange = ReferenceRange { min: Some(11.0), max: Some(15.0), max_inclusive: false };
+
+/// Body mass index variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BmiIndex {
+    /// Quetelet: weight / height².
+    #[default]
+    Classic,
+    /// BMI divided by the upper limit of normal BMI.
+    Prime,
+    /// Trefethen (2013): 1.3 × weight / height^2.5.
+    Trefethen,
+    /// Ponderal (Rohrer) Index: weight / height³.
+    Ponderal,
+}
+
+impl FromStr for BmiIndex {
+    type Err = HealthError;
+
+    /// Parses `classic`, `prime`, `trefethen` (or `new`) and `ponderal` (or `rohrer`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "classic" | "classico" | "quetelet" | "bmi" | "imc" => Ok(BmiIndex::Classic),
+            "prime" | "bmiprime" | "imcprime" => Ok(BmiIndex::Prime),
+            "trefethen" | "new" | "newbmi" | "novoimc" => Ok(BmiIndex::Trefethen),
+            "ponderal" | "ponderalindex" | "rohrer" | "pi" => Ok(BmiIndex::Ponderal),
+            _ => Err(HealthError::Unrecognized {
+                field: "bmi index",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Classification of the Ponderal Index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PonderalCategory {
+    Low,
+    Normal,
+    High,
+}
+
+impl Classification for PonderalCategory {
+    fn message(&self) -> Message {
+        Message::Ponderal(*self)
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            PonderalCategory::Low => RiskLevel::Moderate,
+            PonderalCategory::Normal => RiskLevel::Low,
+            PonderalCategory::High => RiskLevel::High,
+        })
+    }
+}
+
+/// Typed result of a Ponderal Index assessment.
+pub type PonderalAssessment = Assessment<PonderalCategory>;
+
+/// Assessment by one of the [`BmiIndex`] variants. BMI Prime and Trefethen's
+/// index use the BMI categories; the Ponderal Index has its own.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum BmiIndexAssessment {
+    Bmi(BmiAssessment),
+    Ponderal(PonderalAssessment),
+}
+
+/// Calculates and classifies the BMI variants.
+pub struct BmiIndexCalculator;
+
+impl BmiIndexCalculator {
+    /// BMI Prime: BMI / upper limit of normal BMI (25 with the WHO cut-offs).
+    pub fn prime(data: &BmiData, standard: BmiReferenceStandard) -> f32 {
+        BmiCalculator::calculate(data) / Self::upper_normal(standard)
+    }
+
+    /// Trefethen's new BMI: 1.3 × weight (kg) / height^2.5 (m).
+    pub fn trefethen(data: &BmiData) -> f32 {
+        1.3 * data.weight / data.height.powf(2.5)
+    }
+
+    /// Ponderal Index: weight (kg) / height³ (m).
+    pub fn ponderal(data: &BmiData) -> f32 {
+        data.weight / data.height.powi(3)
+    }
+
+    /// Classifies a Ponderal Index against the adult normal range.
+    pub fn classify_ponderal(index: f32) -> PonderalCategory {
+        match (NORMAL_PONDERAL_RANGE.min, NORMAL_PONDERAL_RANGE.max) {
+            (Some(min), _) if index < min => PonderalCategory::Low,
+            (_, Some(max)) if index >= max => PonderalCategory::High,
+            _ => PonderalCategory::Normal,
+        }
+    }
+
+    /// Validates the input and assesses it with the chosen index and the
+    /// WHO international cut-offs.
+    pub fn assess(data: &BmiData, index: BmiIndex) -> Result<BmiIndexAssessment, HealthError> {
+        Self::assess_index(data, index, BmiReferenceStandard::WhoInternational)
+    }
+
+    /// Like [`assess`](Self::assess), with the cut-offs of the chosen standard
+    /// recorded in the result. The Ponderal Index has a single standard.
+    pub fn assess_with_standard(
+        data: &BmiData,
+        index: BmiIndex,
+        standard: BmiReferenceStandard,
+    ) -> Result<BmiIndexAssessment, HealthError> {
+        Ok(match Self::assess_index(data, index, standard)? {
+            BmiIndexAssessment::Bmi(assessment) => {
+                BmiIndexAssessment::Bmi(assessment.with_standard(Standard::Bmi(standard)))
+            }
+            ponderal => ponderal,
+        })
+    }
+
+    fn assess_index(
+        data: &BmiData,
+        index: BmiIndex,
+        standard: BmiReferenceStandard,
+    ) -> Result<BmiIndexAssessment, HealthError> {
+        let bmi = BmiCalculator::try_calculate(data)?;
+        let category = BmiCalculator::classify_with_standard(bmi, standard);
+        let normal = standard.normal_range();
+        let assessment = match index {
+            BmiIndex::Classic => {
+                Assessment::new(bmi, Unit::KilogramsPerSquareMeter, category, normal, Formula::Quetelet)
+            }
+            BmiIndex::Prime => {
+                let upper = Self::upper_normal(standard);
+                let range = ReferenceRange::new(normal.min.map(|min| min / upper), Some(1.0));
+                let prime = error::ensure_finite("bmi prime", bmi / upper)?;
+                Assessment::new(prime, Unit::Ratio, category, range, Formula::BmiPrime)
+            }
+            BmiIndex::Trefethen => {
+                // Trefethen designed the index to be read against the usual BMI cut-offs
+                let value = error::ensure_finite("new bmi", Self::trefethen(data))?;
+                let category = BmiCalculator::classify_with_standard(value, standard);
+                Assessment::new(value, Unit::KilogramsPerSquareMeter, category, normal, Formula::Trefethen)
+            }
+            BmiIndex::Ponderal => {
+                let value = error::ensure_finite("ponderal index", Self::ponderal(data))?;
+                return Ok(BmiIndexAssessment::Ponderal(Assessment::new(
+                    value,
+                    Unit::KilogramsPerCubicMeter,
+                    Self::classify_ponderal(value),
+                    NORMAL_PONDERAL_RANGE,
+                    Formula::Ponderal,
+                )));
+            }
+        };
+        Ok(BmiIndexAssessment::Bmi(assessment))
+    }
+
+    fn upper_normal(standard: BmiReferenceStandard) -> f32 {
+        let cut_offs = standard.cut_offs();
+        cut_offs.overweight.unwrap_or(cut_offs.obesity_grade1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bmi::calculator::BmiCategory;
+
+    fn tall() -> BmiData {
+        BmiData::new(100.0, 2.0).unwrap()
+    }
+
+    #[test]
+    fn test_indices() {
+        // BMI 25 at 2 m: prime 1.0, new BMI 1.3 × 100 / 5.657 = 22.98, ponderal 12.5
+        assert!((BmiIndexCalculator::prime(&tall(), BmiReferenceStandard::WhoInternational) - 1.0).abs() < 1e-6);
+        assert!((BmiIndexCalculator::trefethen(&tall()) - 22.98).abs() < 0.01);
+        assert!((BmiIndexCalculator::ponderal(&tall()) - 12.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_prime_uses_standard_upper_limit() {
+        let prime = BmiIndexCalculator::prime(&tall(), BmiReferenceStandard::WhoAsianPacific);
+        assert!((prime - 25.0 / 23.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_trefethen_reclassifies_tall_people() {
+        let BmiIndexAssessment::Bmi(classic) = BmiIndexCalculator::assess(&tall(), BmiIndex::Classic).unwrap() else {
+            panic!("expected a BMI assessment");
+        };
+        let BmiIndexAssessment::Bmi(new) = BmiIndexCalculator::assess(&tall(), BmiIndex::Trefethen).unwrap() else {
+            panic!("expected a BMI assessment");
+        };
+        assert_eq!(classic.category, BmiCategory::Overweight);
+        assert_eq!(new.category, BmiCategory::NormalWeight);
+        assert_eq!(new.formula, Formula::Trefethen);
+    }
+
+    #[test]
+    fn test_classify_ponderal() {
+        assert_eq!(BmiIndexCalculator::classify_ponderal(10.9), PonderalCategory::Low);
+        assert_eq!(BmiIndexCalculator::classify_ponderal(11.0), PonderalCategory::Normal);
+        assert_eq!(BmiIndexCalculator::classify_ponderal(15.0), PonderalCategory::High);
+        let assessed = BmiIndexCalculator::assess(&tall(), BmiIndex::Ponderal).unwrap();
+        assert!(matches!(assessed, BmiIndexAssessment::Ponderal(a) if a.unit == Unit::KilogramsPerCubicMeter));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("BMI Prime".parse::<BmiIndex>(), Ok(BmiIndex::Prime));
+        assert_eq!("rohrer".parse::<BmiIndex>(), Ok(BmiIndex::Ponderal));
+        assert!("body-volume".parse::<BmiIndex>().is_err());
+    }
+}
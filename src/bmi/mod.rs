@@ -1 +1,5 @@
 pub mod calculator; //This is needed to expose the calculator module
+pub mod pediatric; // BMI-for-age for children and teenagers
+pub mod standard; // Population-specific BMI cut-offs
+pub mod ideal_weight; // Healthy weight range and ideal body weight
+pub mod index; // BMI Prime, Trefethen's new BMI and Ponderal Index
@@ -0,0 +1,395 @@
+//! BMI-for-age of children and teenagers, from growth-reference LMS tables.
+//!
+//! Adult cut-offs do not apply under 20: BMI changes with age during growth,
+//! so it is compared with the reference population of the same age and sex
+//! and reported as a z-score and percentile.
+//!
+//! The tables hold annual (six-monthly in infancy) knots of the WHO 2006/2007
+//! and CDC 2000 BMI-for-age references, rounded and linearly interpolated by
+//! month. They are accurate enough for screening, not for research use.
+
+use std::str::FromStr;
+
+use crate::assessment::{Classification, RiskLevel};
+use crate::bmi::calculator::{BmiCalculator, BmiData};
+use crate::common::sex::Sex;
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::units::{Kilograms, Meters};
+
+/// Age (in years) from which the adult BMI cut-offs apply.
+pub const ADULT_AGE_YEARS: u32 = 20;
+
+/// Growth reference the child is compared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GrowthReference {
+    /// WHO Child Growth Standards (0-5 years) and WHO 2007 reference (5-19 years).
+    #[default]
+    Who,
+    /// CDC 2000 growth charts (2-20 years).
+    Cdc,
+}
+
+impl GrowthReference {
+    /// Ages covered by the reference, in months (inclusive).
+    pub fn age_range_months(&self) -> (u32, u32) {
+        match self {
+            GrowthReference::Who => (0, 228),
+            GrowthReference::Cdc => (24, 240),
+        }
+    }
+
+    fn table(&self, sex: Sex) -> &'static [Lms] {
+        match (self, sex) {
+            (GrowthReference::Who, Sex::Male) => WHO_BOYS,
+            (GrowthReference::Who, Sex::Female) => WHO_GIRLS,
+            (GrowthReference::Cdc, Sex::Male) => CDC_BOYS,
+            (GrowthReference::Cdc, Sex::Female) => CDC_GIRLS,
+        }
+    }
+}
+
+impl FromStr for GrowthReference {
+    type Err = HealthError;
+
+    /// Parses `who` or `cdc`, in any case.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "who" | "oms" => Ok(GrowthReference::Who),
+            "cdc" => Ok(GrowthReference::Cdc),
+            _ => Err(HealthError::Unrecognized {
+                field: "growth reference",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// BMI-for-age category of a child or teenager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PediatricCategory {
+    SevereThinness,
+    Thinness,
+    Normal,
+    /// WHO under 5 only: above +1 SD.
+    PossibleRiskOfOverweight,
+    Overweight,
+    Obesity,
+}
+
+impl Classification for PediatricCategory {
+    fn message(&self) -> Message {
+        Message::Pediatric(*self)
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            PediatricCategory::SevereThinness => RiskLevel::High,
+            PediatricCategory::Thinness
+            | PediatricCategory::PossibleRiskOfOverweight
+            | PediatricCategory::Overweight => RiskLevel::Moderate,
+            PediatricCategory::Normal => RiskLevel::Low,
+            PediatricCategory::Obesity => RiskLevel::High,
+        })
+    }
+}
+
+/// Input of a BMI-for-age assessment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PediatricBmiData {
+    pub weight: f32,     // em kg
+    pub height: f32,     // em metros
+    pub age_months: u32, // idade em meses completos
+    pub sex: Sex,
+}
+
+impl PediatricBmiData {
+    /// Creates validated input; the age must be under 20 years.
+    pub fn new(
+        weight: impl Into<Kilograms>,
+        height: impl Into<Meters>,
+        age_months: u32,
+        sex: Sex,
+    ) -> Result<Self, HealthError> {
+        let data = PediatricBmiData {
+            weight: weight.into().0,
+            height: height.into().0,
+            age_months,
+            sex,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks weight, height and that the age is under 20 years.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("weight", self.weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        if self.age_months >= ADULT_AGE_YEARS * 12 {
+            return Err(HealthError::Implausible {
+                field: "age in months",
+                value: self.age_months as f32,
+                min: 0.0,
+                max: (ADULT_AGE_YEARS * 12 - 1) as f32,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Result of a BMI-for-age assessment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PediatricBmiResult {
+    pub bmi: f32,
+    pub age_months: u32,
+    pub reference: GrowthReference,
+    pub z_score: f32,
+    /// Percentile of the reference population (0-100).
+    pub percentile: f32,
+    pub category: PediatricCategory,
+    pub risk: Option<RiskLevel>,
+}
+
+/// Box-Cox power (L), median (M) and coefficient of variation (S) at an age.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lms {
+    pub age_months: u32,
+    pub l: f32,
+    pub m: f32,
+    pub s: f32,
+}
+
+const fn lms(age_months: u32, l: f32, m: f32, s: f32) -> Lms {
+    Lms { age_months, l, m, s }
+}
+
+const WHO_BOYS: &[Lms] = &[
+    lms(0, -0.305, 13.41, 0.0956), lms(6, 0.030, 17.30, 0.0808), lms(12, -0.200, 16.90, 0.0806),
+    lms(24, -0.600, 16.00, 0.0813), lms(36, -0.650, 15.60, 0.0804), lms(48, -0.700, 15.30, 0.0810),
+    lms(60, -0.739, 15.26, 0.0839), lms(72, -0.860, 15.30, 0.0855), lms(84, -0.990, 15.50, 0.0886),
+    lms(96, -1.120, 15.70, 0.0922), lms(108, -1.240, 16.00, 0.0961), lms(120, -1.350, 16.40, 0.1000),
+    lms(132, -1.440, 16.90, 0.1038), lms(144, -1.500, 17.50, 0.1072), lms(156, -1.520, 18.20, 0.1099),
+    lms(168, -1.490, 19.00, 0.1117), lms(180, -1.430, 19.80, 0.1126), lms(192, -1.350, 20.50, 0.1128),
+    lms(204, -1.250, 21.10, 0.1125), lms(216, -1.140, 21.70, 0.1119), lms(228, -1.020, 22.20, 0.1111),
+];
+
+const WHO_GIRLS: &[Lms] = &[
+    lms(0, -0.063, 13.34, 0.0917), lms(6, -0.100, 16.90, 0.0840), lms(12, -0.350, 16.40, 0.0850),
+    lms(24, -0.600, 15.70, 0.0859), lms(36, -0.700, 15.40, 0.0872), lms(48, -0.750, 15.20, 0.0891),
+    lms(60, -0.889, 15.24, 0.0969), lms(72, -0.980, 15.30, 0.1001), lms(84, -1.080, 15.40, 0.1044),
+    lms(96, -1.170, 15.70, 0.1092), lms(108, -1.240, 16.10, 0.1143), lms(120, -1.280, 16.60, 0.1192),
+    lms(132, -1.280, 17.20, 0.1236), lms(144, -1.240, 18.00, 0.1270), lms(156, -1.170, 18.80, 0.1293),
+    lms(168, -1.090, 19.60, 0.1306), lms(180, -1.010, 20.20, 0.1312), lms(192, -0.940, 20.70, 0.1313),
+    lms(204, -0.880, 21.00, 0.1312), lms(216, -0.830, 21.30, 0.1309), lms(228, -0.790, 21.40, 0.1306),
+];
+
+const CDC_BOYS: &[Lms] = &[
+    lms(24, -2.010, 16.57, 0.0805), lms(36, -1.730, 16.00, 0.0763), lms(48, -1.620, 15.60, 0.0775),
+    lms(60, -1.700, 15.40, 0.0830), lms(72, -1.860, 15.30, 0.0920), lms(84, -2.020, 15.50, 0.1030),
+    lms(96, -2.150, 15.80, 0.1140), lms(108, -2.230, 16.20, 0.1250), lms(120, -2.260, 16.60, 0.1330),
+    lms(132, -2.240, 17.20, 0.1380), lms(144, -2.170, 17.80, 0.1400), lms(156, -2.060, 18.50, 0.1400),
+    lms(168, -1.920, 19.20, 0.1410), lms(180, -1.760, 19.90, 0.1450), lms(192, -1.590, 20.50, 0.1470),
+    lms(204, -1.420, 21.30, 0.1490), lms(216, -1.270, 21.90, 0.1500), lms(228, -1.140, 22.40, 0.1510),
+    lms(240, -1.030, 22.90, 0.1520),
+];
+
+const CDC_GIRLS: &[Lms] = &[
+    lms(24, -0.990, 16.42, 0.0850), lms(36, -1.140, 15.70, 0.0850), lms(48, -1.390, 15.30, 0.0900),
+    lms(60, -1.630, 15.20, 0.0980), lms(72, -1.840, 15.20, 0.1070), lms(84, -2.000, 15.40, 0.1160),
+    lms(96, -2.110, 15.80, 0.1250), lms(108, -2.170, 16.30, 0.1320), lms(120, -2.190, 16.90, 0.1370),
+    lms(132, -2.160, 17.50, 0.1400), lms(144, -2.100, 18.10, 0.1420), lms(156, -2.020, 18.70, 0.1430),
+    lms(168, -1.920, 19.40, 0.1440), lms(180, -1.800, 19.90, 0.1450), lms(192, -1.670, 20.40, 0.1500),
+    lms(204, -1.540, 20.80, 0.1550), lms(216, -1.400, 21.00, 0.1600), lms(228, -1.270, 21.30, 0.1650),
+    lms(240, -1.140, 21.50, 0.1700),
+];
+
+/// Calculator for BMI-for-age of people under 20.
+pub struct PediatricBmiCalculator;
+
+impl PediatricBmiCalculator {
+    /// LMS parameters at an age, interpolated between the table's knots.
+    /// The WHO reference ends at 19 years; older teenagers get its last knot.
+    pub fn lms(reference: GrowthReference, sex: Sex, age_months: u32) -> Result<Lms, HealthError> {
+        let (min, covered) = reference.age_range_months();
+        let max = covered.max(ADULT_AGE_YEARS * 12 - 1);
+        if age_months < min || age_months > max {
+            return Err(HealthError::Implausible {
+                field: "age in months",
+                value: age_months as f32,
+                min: min as f32,
+                max: max as f32,
+            });
+        }
+        let table = reference.table(sex);
+        if age_months > covered {
+            let last = table[table.len() - 1];
+            return Ok(lms(age_months, last.l, last.m, last.s));
+        }
+        let upper = table.iter().position(|knot| knot.age_months >= age_months).unwrap_or(table.len() - 1);
+        if upper == 0 || table[upper].age_months == age_months {
+            return Ok(table[upper]);
+        }
+        let (a, b) = (table[upper - 1], table[upper]);
+        let t = (age_months - a.age_months) as f32 / (b.age_months - a.age_months) as f32;
+        let lerp = |x: f32, y: f32| x + (y - x) * t;
+        Ok(lms(age_months, lerp(a.l, b.l), lerp(a.m, b.m), lerp(a.s, b.s)))
+    }
+
+    /// BMI value at z-score `z` for the given LMS parameters.
+    pub fn bmi_at(lms: &Lms, z: f32) -> f32 {
+        if lms.l.abs() < 1e-6 {
+            lms.m * (lms.s * z).exp()
+        } else {
+            lms.m * (1.0 + lms.l * lms.s * z).powf(1.0 / lms.l)
+        }
+    }
+
+    /// LMS z-score of a BMI. For WHO, values beyond ±3 use the WHO restricted
+    /// extrapolation, which keeps the extreme tails from being compressed.
+    pub fn z_score(bmi: f32, lms: &Lms, reference: GrowthReference) -> f32 {
+        let z = if lms.l.abs() < 1e-6 {
+            (bmi / lms.m).ln() / lms.s
+        } else {
+            ((bmi / lms.m).powf(lms.l) - 1.0) / (lms.l * lms.s)
+        };
+        if reference != GrowthReference::Who || z.abs() <= 3.0 {
+            return z;
+        }
+        let sign = z.signum();
+        let sd3 = Self::bmi_at(lms, 3.0 * sign);
+        let sd23 = (sd3 - Self::bmi_at(lms, 2.0 * sign)).abs();
+        sign * 3.0 + (bmi - sd3) / sd23
+    }
+
+    /// Percentile (0-100) of a z-score under the standard normal distribution.
+    pub fn percentile(z: f32) -> f32 {
+        let z = z as f64;
+        (50.0 * (1.0 + erf(z / std::f64::consts::SQRT_2))) as f32
+    }
+
+    /// Classifies a z-score: WHO cut-offs (±2 SD, +1 SD over age 5, +1/+2/+3 SD
+    /// under 5) or CDC percentiles (5th, 85th, 95th).
+    pub fn classify(z: f32, age_months: u32, reference: GrowthReference) -> PediatricCategory {
+        match reference {
+            GrowthReference::Who => {
+                let (overweight, obesity) = if age_months < 60 { (2.0, 3.0) } else { (1.0, 2.0) };
+                match z {
+                    z if z < -3.0 => PediatricCategory::SevereThinness,
+                    z if z < -2.0 => PediatricCategory::Thinness,
+                    z if z > obesity => PediatricCategory::Obesity,
+                    z if z > overweight => PediatricCategory::Overweight,
+                    z if age_months < 60 && z > 1.0 => PediatricCategory::PossibleRiskOfOverweight,
+                    _ => PediatricCategory::Normal,
+                }
+            }
+            GrowthReference::Cdc => match Self::percentile(z) {
+                p if p < 5.0 => PediatricCategory::Thinness,
+                p if p < 85.0 => PediatricCategory::Normal,
+                p if p < 95.0 => PediatricCategory::Overweight,
+                _ => PediatricCategory::Obesity,
+            },
+        }
+    }
+
+    /// Validates the input and computes BMI, z-score, percentile and category.
+    pub fn assess(data: &PediatricBmiData, reference: GrowthReference) -> Result<PediatricBmiResult, HealthError> {
+        data.validate()?;
+        let bmi = BmiCalculator::try_calculate(&BmiData { weight: data.weight, height: data.height })?;
+        let lms = Self::lms(reference, data.sex, data.age_months)?;
+        let z_score = error::ensure_finite("z-score", Self::z_score(bmi, &lms, reference))?;
+        let category = Self::classify(z_score, data.age_months, reference);
+        Ok(PediatricBmiResult {
+            bmi,
+            age_months: data.age_months,
+            reference,
+            z_score,
+            percentile: Self::percentile(z_score),
+            category,
+            risk: category.risk(),
+        })
+    }
+}
+
+/// Error function (Abramowitz & Stegun 7.1.26, absolute error below 1.5e-7).
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+    let poly = t * (0.254_829_592 + t * (-0.284_496_736 + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let y = 1.0 - poly * (-x * x).exp();
+    if x < 0.0 { -y } else { y }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lms_interpolation() {
+        let knot = PediatricBmiCalculator::lms(GrowthReference::Who, Sex::Male, 120).unwrap();
+        assert_eq!(knot.m, 16.4);
+        let between = PediatricBmiCalculator::lms(GrowthReference::Who, Sex::Male, 126).unwrap();
+        assert!((between.m - 16.65).abs() < 1e-4);
+        assert!(PediatricBmiCalculator::lms(GrowthReference::Cdc, Sex::Female, 12).is_err());
+    }
+
+    #[test]
+    fn test_median_bmi_is_50th_percentile() {
+        let data = PediatricBmiData::new(16.4 * 1.4 * 1.4, 1.4, 120, Sex::Male).unwrap();
+        let result = PediatricBmiCalculator::assess(&data, GrowthReference::Who).unwrap();
+        assert!(result.z_score.abs() < 1e-3);
+        assert!((result.percentile - 50.0).abs() < 0.1);
+        assert_eq!(result.category, PediatricCategory::Normal);
+    }
+
+    #[test]
+    fn test_percentile() {
+        assert!((PediatricBmiCalculator::percentile(1.645) - 95.0).abs() < 0.05);
+        assert!((PediatricBmiCalculator::percentile(-1.0) - 15.87).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_who_categories() {
+        assert_eq!(PediatricBmiCalculator::classify(1.5, 120, GrowthReference::Who), PediatricCategory::Overweight);
+        assert_eq!(
+            PediatricBmiCalculator::classify(1.5, 36, GrowthReference::Who),
+            PediatricCategory::PossibleRiskOfOverweight
+        );
+        assert_eq!(PediatricBmiCalculator::classify(0.5, 36, GrowthReference::Who), PediatricCategory::Normal);
+        assert_eq!(PediatricBmiCalculator::classify(2.5, 120, GrowthReference::Who), PediatricCategory::Obesity);
+        assert_eq!(PediatricBmiCalculator::classify(-3.5, 120, GrowthReference::Who), PediatricCategory::SevereThinness);
+    }
+
+    #[test]
+    fn test_cdc_obesity_at_95th_percentile() {
+        // A 10-year-old boy with an adult-normal BMI of 24 is obese for his age
+        let data = PediatricBmiData::new(24.0 * 1.4 * 1.4, 1.4, 120, Sex::Male).unwrap();
+        let result = PediatricBmiCalculator::assess(&data, GrowthReference::Cdc).unwrap();
+        assert!(result.percentile >= 95.0);
+        assert_eq!(result.category, PediatricCategory::Obesity);
+    }
+
+    #[test]
+    fn test_who_restricted_extrapolation_is_continuous() {
+        let lms = PediatricBmiCalculator::lms(GrowthReference::Who, Sex::Female, 144).unwrap();
+        let sd3 = PediatricBmiCalculator::bmi_at(&lms, 3.0);
+        let z = PediatricBmiCalculator::z_score(sd3, &lms, GrowthReference::Who);
+        assert!((z - 3.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_who_last_knot_until_adulthood() {
+        let last = PediatricBmiCalculator::lms(GrowthReference::Who, Sex::Male, 228).unwrap();
+        let older = PediatricBmiCalculator::lms(GrowthReference::Who, Sex::Male, 234).unwrap();
+        assert_eq!((older.l, older.m, older.s), (last.l, last.m, last.s));
+        assert!(PediatricBmiCalculator::lms(GrowthReference::Who, Sex::Male, 240).is_err());
+    }
+
+    #[test]
+    fn test_rejects_adults() {
+        let result = PediatricBmiData::new(70.0, 1.75, 240, Sex::Male);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "age in months", .. })));
+    }
+}
@@ -0,0 +1,115 @@
+//! Population-specific BMI cut-offs.
+//!
+//! Asian populations have more body fat and higher cardiometabolic risk at a
+//! given BMI, so WHO and national bodies recommend lower action points.
+
+use std::str::FromStr;
+
+use crate::assessment::ReferenceRange;
+use crate::error::HealthError;
+
+/// Set of BMI cut-offs used to classify an adult.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BmiReferenceStandard {
+    /// WHO international classification: 18.5, 25, 30, 35, 40.
+    #[default]
+    WhoInternational,
+    /// WHO Western Pacific Region (2000): 18.5, 23, 25, 30.
+    WhoAsianPacific,
+    /// WHO expert consultation for Asian populations (2004): 18.5, 23, 27.5, 32.5, 37.5.
+    WhoExpertConsultation,
+    /// Japan Society for the Study of Obesity (JASSO): 18.5, 25, 30, 35.
+    Jasso,
+}
+
+/// Lower bounds of each category above underweight; `None` skips the category.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BmiCutOffs {
+    pub normal: f32,
+    pub overweight: Option<f32>,
+    pub obesity_grade1: f32,
+    pub obesity_grade2: f32,
+    pub obesity_grade3: Option<f32>,
+}
+
+impl BmiReferenceStandard {
+    /// Cut-offs of the standard, in kg/m².
+    pub fn cut_offs(&self) -> BmiCutOffs {
+        match self {
+            BmiReferenceStandard::WhoInternational => BmiCutOffs {
+                normal: 18.5,
+                overweight: Some(25.0),
+                obesity_grade1: 30.0,
+                obesity_grade2: 35.0,
+                obesity_grade3: Some(40.0),
+            },
+            BmiReferenceStandard::WhoAsianPacific => BmiCutOffs {
+                normal: 18.5,
+                overweight: Some(23.0),
+                obesity_grade1: 25.0,
+                obesity_grade2: 30.0,
+                obesity_grade3: None,
+            },
+            BmiReferenceStandard::WhoExpertConsultation => BmiCutOffs {
+                normal: 18.5,
+                overweight: Some(23.0),
+                obesity_grade1: 27.5,
+                obesity_grade2: 32.5,
+                obesity_grade3: Some(37.5),
+            },
+            // JASSO has no "overweight" band; obesity degrees 3 and 4 are grouped as grade 3
+            BmiReferenceStandard::Jasso => BmiCutOffs {
+                normal: 18.5,
+                overweight: None,
+                obesity_grade1: 25.0,
+                obesity_grade2: 30.0,
+                obesity_grade3: Some(35.0),
+            },
+        }
+    }
+
+    /// Normal weight range of the standard.
+    pub fn normal_range(&self) -> ReferenceRange {
+        let cut_offs = self.cut_offs();
+        ReferenceRange::new(Some(cut_offs.normal), Some(cut_offs.overweight.unwrap_or(cut_offs.obesity_grade1)))
+    }
+}
+
+impl FromStr for BmiReferenceStandard {
+    type Err = HealthError;
+
+    /// Parses `who`, `asian-pacific`, `who-expert` (or `asian`) and `jasso` (or `japan`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "who" | "oms" | "whointernational" | "international" => Ok(BmiReferenceStandard::WhoInternational),
+            "asianpacific" | "whoasianpacific" | "wpro" => Ok(BmiReferenceStandard::WhoAsianPacific),
+            "whoexpert" | "expert" | "whoexpertconsultation" | "asian" => Ok(BmiReferenceStandard::WhoExpertConsultation),
+            "jasso" | "japan" | "japanese" => Ok(BmiReferenceStandard::Jasso),
+            _ => Err(HealthError::Unrecognized {
+                field: "bmi standard",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_ranges() {
+        assert_eq!(BmiReferenceStandard::WhoInternational.normal_range(), ReferenceRange::new(Some(18.5), Some(25.0)));
+        assert_eq!(BmiReferenceStandard::WhoExpertConsultation.normal_range(), ReferenceRange::new(Some(18.5), Some(23.0)));
+        assert_eq!(BmiReferenceStandard::Jasso.normal_range(), ReferenceRange::new(Some(18.5), Some(25.0)));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("Asian-Pacific".parse::<BmiReferenceStandard>(), Ok(BmiReferenceStandard::WhoAsianPacific));
+        assert_eq!("jasso".parse::<BmiReferenceStandard>(), Ok(BmiReferenceStandard::Jasso));
+        assert!("martian".parse::<BmiReferenceStandard>().is_err());
+    }
+}
@@ -0,0 +1,137 @@
+//! CUN-BAE and Relative Fat Mass (RFM) body fat estimators.
+//!
+//! Both need only a scale and a tape measure and agree better with DXA than
+//! the Deurenberg formula: CUN-BAE refines the BMI with age and sex, and RFM
+//! uses the height-to-waist ratio.
+
+use crate::assessment::Formula;
+use crate::body_fat::{plausible_pgc, BodyFatAssessment, BodyFatCalculator, BodyFatData};
+use crate::error::{self, HealthError};
+use crate::metabolism::tmb::Gender;
+use crate::units::{Centimeters, Meters};
+
+/// Input of the Relative Fat Mass; height in m, waist in cm.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RfmData {
+    pub height: f32,
+    pub waist: f32,
+    pub age: u32,
+    pub gender: Gender,
+}
+
+impl RfmData {
+    /// Creates validated input.
+    pub fn new(height: impl Into<Meters>, waist: impl Into<Centimeters>, age: u32, gender: Gender) -> Result<Self, HealthError> {
+        let data = RfmData { height: height.into().0, waist: waist.into().0, age, gender };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that height (m), waist (cm) and age are plausible.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        error::validate_measurement("waist", self.waist, error::CIRCUMFERENCE_RANGE_CM)?;
+        error::validate_age(self.age)?;
+        Ok(())
+    }
+}
+
+/// CUN-BAE (Clínica Universidad de Navarra, Gómez-Ambrosi et al., 2012).
+pub struct CunBaeCalculator;
+
+impl CunBaeCalculator {
+    /// Calculates the PGC from BMI, age and sex (sex = 0 for men, 1 for women):
+    ///
+    /// −44.988 + 0.503·age + 10.689·sex + 3.172·BMI − 0.026·BMI² + 0.181·BMI·sex
+    /// − 0.02·BMI·age − 0.005·BMI²·sex + 0.00021·BMI²·age
+    pub fn calculate(bmi: f32, age: u32, gender: &Gender) -> f32 {
+        let sex = match gender {
+            Gender::Male => 0.0,
+            Gender::Female => 1.0,
+        };
+        let age = age as f32;
+        let bmi2 = bmi * bmi;
+        -44.988 + 0.503 * age + 10.689 * sex + 3.172 * bmi - 0.026 * bmi2 + 0.181 * bmi * sex
+            - 0.02 * bmi * age
+            - 0.005 * bmi2 * sex
+            + 0.00021 * bmi2 * age
+    }
+
+    /// Validates the input and calculates the PGC.
+    pub fn try_calculate(data: &BodyFatData) -> Result<f32, HealthError> {
+        let bmi = BodyFatCalculator::try_calculate_bmi(data)?;
+        plausible_pgc(Self::calculate(bmi, data.age, &data.gender))
+    }
+
+    /// Validates the input and classifies the PGC with the shared sex and age tables.
+    pub fn assess(data: &BodyFatData) -> Result<BodyFatAssessment, HealthError> {
+        let pgc = Self::try_calculate(data)?;
+        Ok(BodyFatCalculator::assess_pgc(pgc, data.age, &data.gender, Formula::CunBae))
+    }
+}
+
+/// Relative Fat Mass (Woolcott & Bergman, 2018).
+pub struct RfmCalculator;
+
+impl RfmCalculator {
+    /// Calculates the PGC: 64 (men) or 76 (women) − 20 × height / waist.
+    pub fn calculate(data: &RfmData) -> f32 {
+        let intercept = match data.gender {
+            Gender::Male => 64.0,
+            Gender::Female => 76.0,
+        };
+        intercept - 20.0 * Centimeters::from(Meters(data.height)).0 / data.waist
+    }
+
+    /// Validates the input and calculates the PGC.
+    pub fn try_calculate(data: &RfmData) -> Result<f32, HealthError> {
+        data.validate()?;
+        plausible_pgc(Self::calculate(data))
+    }
+
+    /// Validates the input and classifies the PGC with the shared sex and age tables.
+    pub fn assess(data: &RfmData) -> Result<BodyFatAssessment, HealthError> {
+        let pgc = Self::try_calculate(data)?;
+        Ok(BodyFatCalculator::assess_pgc(pgc, data.age, &data.gender, Formula::RelativeFatMass))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cun_bae_male() {
+        // BMI 25, age 40: −44.988 + 20.12 + 79.3 − 16.25 − 20 + 5.25 = 23.43
+        assert!((CunBaeCalculator::calculate(25.0, 40, &Gender::Male) - 23.432).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_cun_bae_female_reads_higher() {
+        let male = CunBaeCalculator::calculate(25.0, 40, &Gender::Male);
+        let female = CunBaeCalculator::calculate(25.0, 40, &Gender::Female);
+        // 10.689 + 4.525 − 3.125 = 12.089 more for women
+        assert!((female - male - 12.089).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_rfm() {
+        // 64 − 20 × 175 / 85 = 22.82
+        let male = RfmData::new(1.75, 85.0, 30, Gender::Male).unwrap();
+        assert!((RfmCalculator::calculate(&male) - 22.82).abs() < 0.01);
+        let female = RfmData::new(1.65, 75.0, 30, Gender::Female).unwrap();
+        assert!((RfmCalculator::calculate(&female) - 32.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_rfm_rejects_implausible_result() {
+        let data = RfmData { height: 2.0, waist: 50.0, age: 30, gender: Gender::Male };
+        assert_eq!(RfmCalculator::try_calculate(&data), Err(HealthError::InvalidResult { calculation: "pgc" }));
+    }
+
+    #[test]
+    fn test_assess_records_formula() {
+        let data = BodyFatData::new(80.0, 1.80, 40, Gender::Male).unwrap();
+        assert_eq!(CunBaeCalculator::assess(&data).unwrap().formula, Formula::CunBae);
+    }
+}
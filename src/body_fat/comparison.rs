@@ -0,0 +1,208 @@
+//! Runs every body fat estimator the available measurements allow and
+//! summarizes how far apart they are.
+
+use crate::assessment::Formula;
+use crate::body_fat::anthropometric::{CunBaeCalculator, RfmCalculator, RfmData};
+use crate::body_fat::navy::{NavyBodyFatCalculator, NavyBodyFatData};
+use crate::body_fat::skinfold::{DensityEquation, SkinfoldCalculator, SkinfoldData, SkinfoldMethod, Skinfolds};
+use crate::body_fat::{BodyFatAssessment, BodyFatCalculator, BodyFatData};
+use crate::error::HealthError;
+use crate::metabolism::tmb::Gender;
+use crate::units::{Centimeters, Kilograms, Meters};
+
+/// Skinfold methods tried by the comparison, when their sites are measured.
+const SKINFOLD_METHODS: [SkinfoldMethod; 4] = [
+    SkinfoldMethod::JacksonPollock3,
+    SkinfoldMethod::JacksonPollock7,
+    SkinfoldMethod::DurninWomersley,
+    SkinfoldMethod::Parrillo,
+];
+
+/// Everything known about a person; optional measurements enable more estimators.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyFatProfile {
+    pub weight: f32,
+    pub height: f32,
+    pub age: u32,
+    pub gender: Gender,
+    pub neck: Option<f32>,
+    pub waist: Option<f32>,
+    pub hip: Option<f32>,
+    pub skinfolds: Skinfolds,
+}
+
+impl BodyFatProfile {
+    /// Profile with the measurements every estimator gets; the rest are added
+    /// with the `with_*` methods and validated when the comparison runs.
+    pub fn new(weight: impl Into<Kilograms>, height: impl Into<Meters>, age: u32, gender: Gender) -> Self {
+        BodyFatProfile {
+            weight: weight.into().0,
+            height: height.into().0,
+            age,
+            gender,
+            neck: None,
+            waist: None,
+            hip: None,
+            skinfolds: Skinfolds::default(),
+        }
+    }
+
+    pub fn with_neck(mut self, neck: impl Into<Centimeters>) -> Self {
+        self.neck = Some(neck.into().0);
+        self
+    }
+
+    pub fn with_waist(mut self, waist: impl Into<Centimeters>) -> Self {
+        self.waist = Some(waist.into().0);
+        self
+    }
+
+    pub fn with_hip(mut self, hip: impl Into<Centimeters>) -> Self {
+        self.hip = Some(hip.into().0);
+        self
+    }
+
+    pub fn with_skinfolds(mut self, skinfolds: Skinfolds) -> Self {
+        self.skinfolds = skinfolds;
+        self
+    }
+}
+
+/// Estimates of every applicable method and their spread.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyFatComparison {
+    pub estimates: Vec<BodyFatAssessment>,
+    /// Estimators that ran but gave an implausible result, left out of the summary.
+    pub skipped: Vec<Formula>,
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    /// Difference between the highest and lowest estimate, in percentage points.
+    pub spread: f32,
+}
+
+/// Compares the body fat estimators.
+pub struct BodyFatComparator;
+
+impl BodyFatComparator {
+    /// Runs Deurenberg and CUN-BAE, plus RFM with the waist, US Navy with the
+    /// neck and waist (and hip for women), and each skinfold method (Siri)
+    /// whose sites are all measured. Invalid measurements are errors; estimators
+    /// whose result is implausible are skipped, and it fails only if all are.
+    pub fn compare(profile: &BodyFatProfile) -> Result<BodyFatComparison, HealthError> {
+        let (age, gender) = (profile.age, profile.gender);
+        let data = BodyFatData::new(profile.weight, profile.height, age, gender)?;
+        let mut results = vec![
+            (Formula::Deurenberg, BodyFatCalculator::assess(&data)),
+            (Formula::CunBae, CunBaeCalculator::assess(&data)),
+        ];
+
+        if let Some(waist) = profile.waist {
+            let rfm = RfmData::new(profile.height, Centimeters(waist), age, gender)?;
+            results.push((Formula::RelativeFatMass, RfmCalculator::assess(&rfm)));
+        }
+        if let (Some(neck), Some(waist)) = (profile.neck, profile.waist) {
+            if gender == Gender::Male || profile.hip.is_some() {
+                let hip = profile.hip.map(Centimeters);
+                let navy = NavyBodyFatData::new(profile.height, Centimeters(neck), Centimeters(waist), hip, age, gender)?;
+                results.push((Formula::UsNavy, NavyBodyFatCalculator::assess(&navy)));
+            }
+        }
+        let skinfolds = SkinfoldData::new(profile.skinfolds, profile.weight, age, gender)?;
+        for method in SKINFOLD_METHODS {
+            if method.sites(gender).iter().all(|site| profile.skinfolds.get(*site).is_some()) {
+                results.push((method.formula(), SkinfoldCalculator::assess(&skinfolds, method, DensityEquation::Siri)));
+            }
+        }
+
+        let (mut estimates, mut skipped) = (Vec::new(), Vec::new());
+        for (formula, result) in results {
+            match result {
+                Ok(estimate) => estimates.push(estimate),
+                Err(HealthError::InvalidResult { .. }) => skipped.push(formula),
+                Err(err) => return Err(err),
+            }
+        }
+        if estimates.is_empty() {
+            return Err(HealthError::InvalidResult { calculation: "pgc" });
+        }
+        let values = estimates.iter().map(|estimate| estimate.value);
+        let min = values.clone().fold(f32::INFINITY, f32::min);
+        let max = values.clone().fold(f32::NEG_INFINITY, f32::max);
+        let mean = values.sum::<f32>() / estimates.len() as f32;
+        Ok(BodyFatComparison { estimates, skipped, min, max, mean, spread: max - min })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::body_fat::skinfold::SkinfoldSite;
+
+    #[test]
+    fn test_compare_runs_available_estimators() {
+        let profile = BodyFatProfile::new(70.0, 1.75, 30, Gender::Male);
+        let comparison = BodyFatComparator::compare(&profile).unwrap();
+        let formulas: Vec<Formula> = comparison.estimates.iter().map(|e| e.formula).collect();
+        assert_eq!(formulas, vec![Formula::Deurenberg, Formula::CunBae]);
+
+        let skinfolds = Skinfolds::default()
+            .with(SkinfoldSite::Chest, 10.0)
+            .with(SkinfoldSite::Abdomen, 20.0)
+            .with(SkinfoldSite::Thigh, 15.0);
+        let profile = profile.with_neck(38.0).with_waist(85.0).with_skinfolds(skinfolds);
+        let formulas: Vec<Formula> =
+            BodyFatComparator::compare(&profile).unwrap().estimates.iter().map(|e| e.formula).collect();
+        assert_eq!(
+            formulas,
+            vec![Formula::Deurenberg, Formula::CunBae, Formula::RelativeFatMass, Formula::UsNavy, Formula::JacksonPollock3]
+        );
+    }
+
+    #[test]
+    fn test_navy_skipped_for_women_without_hip() {
+        let profile = BodyFatProfile::new(60.0, 1.65, 30, Gender::Female).with_neck(32.0).with_waist(75.0);
+        let comparison = BodyFatComparator::compare(&profile).unwrap();
+        assert!(comparison.estimates.iter().all(|e| e.formula != Formula::UsNavy));
+    }
+
+    #[test]
+    fn test_spread() {
+        let comparison = BodyFatComparator::compare(&BodyFatProfile::new(70.0, 1.75, 30, Gender::Male)).unwrap();
+        let (deurenberg, cun_bae) = (comparison.estimates[0].value, comparison.estimates[1].value);
+        assert!((comparison.spread - (deurenberg - cun_bae).abs()).abs() < 1e-4);
+        assert!((comparison.mean - (deurenberg + cun_bae) / 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_implausible_estimator_is_skipped() {
+        // CUN-BAE and US Navy come out below 0% for this very lean build (BMI 13.9)
+        let profile = BodyFatProfile::new(45.0, 1.80, 20, Gender::Male).with_neck(40.0).with_waist(60.0);
+        let comparison = BodyFatComparator::compare(&profile).unwrap();
+        assert_eq!(comparison.skipped, vec![Formula::CunBae, Formula::UsNavy]);
+        let formulas: Vec<Formula> = comparison.estimates.iter().map(|e| e.formula).collect();
+        assert_eq!(formulas, vec![Formula::Deurenberg, Formula::RelativeFatMass]);
+        let (deurenberg, rfm) = (comparison.estimates[0].value, comparison.estimates[1].value);
+        assert_eq!(comparison.min, deurenberg.min(rfm));
+        assert!((comparison.mean - (deurenberg + rfm) / 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_implausible_deurenberg_is_skipped() {
+        // BMI 6.2 puts Deurenberg at -4.2%; RFM with the waist is still plausible
+        let profile = BodyFatProfile::new(20.0, 1.80, 20, Gender::Male).with_waist(60.0);
+        let comparison = BodyFatComparator::compare(&profile).unwrap();
+        assert_eq!(comparison.skipped, vec![Formula::Deurenberg, Formula::CunBae]);
+        assert_eq!(comparison.estimates.len(), 1);
+        assert_eq!(comparison.estimates[0].formula, Formula::RelativeFatMass);
+        assert_eq!(comparison.spread, 0.0);
+    }
+
+    #[test]
+    fn test_invalid_optional_measurement_is_an_error() {
+        let profile = BodyFatProfile::new(70.0, 1.75, 30, Gender::Male).with_waist(-5.0);
+        assert!(matches!(BodyFatComparator::compare(&profile), Err(HealthError::Negative { field: "waist", .. })));
+    }
+}
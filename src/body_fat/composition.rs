@@ -0,0 +1,278 @@
+//! Body composition from a body fat estimate: fat mass, fat-free mass,
+//! lean body mass formulas and the Fat-Free Mass Index (FFMI).
+//!
+//! The fat-free mass can feed the lean-mass TMB equations (Katch-McArdle and
+//! Cunningham) instead of the default Deurenberg-based estimate.
+
+use std::str::FromStr;
+
+use crate::assessment::{Classification, Formula, RiskLevel};
+use crate::body_fat::BodyFatAssessment;
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::metabolism::bmr::{BmrFormula, BmrFormulaName};
+use crate::metabolism::tmb::Gender;
+use crate::units::{Centimeters, Kilograms, Meters};
+
+/// Height to which the normalized FFMI is adjusted, in meters.
+const FFMI_REFERENCE_HEIGHT_M: f32 = 1.8;
+
+/// Lean body mass equation based on weight and height only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LeanMassFormula {
+    /// Boer (1984).
+    Boer,
+    /// James (1976).
+    James,
+    /// Hume (1966).
+    Hume,
+}
+
+impl LeanMassFormula {
+    /// Every formula.
+    pub const ALL: [LeanMassFormula; 3] = [LeanMassFormula::Boer, LeanMassFormula::James, LeanMassFormula::Hume];
+
+    /// Formula reported in results.
+    pub fn formula(&self) -> Formula {
+        match self {
+            LeanMassFormula::Boer => Formula::Boer,
+            LeanMassFormula::James => Formula::James,
+            LeanMassFormula::Hume => Formula::Hume,
+        }
+    }
+
+    /// Lean body mass in kg, from weight (kg) and height (m).
+    pub fn calculate(&self, weight: f32, height: f32, gender: Gender) -> f32 {
+        let height_cm = Centimeters::from(Meters(height)).0;
+        match (self, gender) {
+            (LeanMassFormula::Boer, Gender::Male) => 0.407 * weight + 0.267 * height_cm - 19.2,
+            (LeanMassFormula::Boer, Gender::Female) => 0.252 * weight + 0.473 * height_cm - 48.3,
+            (LeanMassFormula::James, Gender::Male) => 1.1 * weight - 128.0 * (weight / height_cm).powi(2),
+            (LeanMassFormula::James, Gender::Female) => 1.07 * weight - 148.0 * (weight / height_cm).powi(2),
+            (LeanMassFormula::Hume, Gender::Male) => 0.32810 * weight + 0.33929 * height_cm - 29.5336,
+            (LeanMassFormula::Hume, Gender::Female) => 0.29569 * weight + 0.41813 * height_cm - 43.2933,
+        }
+    }
+
+    /// Lean body mass in kg, rejecting the implausible values James gives at high BMI.
+    pub fn try_calculate(&self, weight: f32, height: f32, gender: Gender) -> Result<f32, HealthError> {
+        error::validate_measurement("lean mass", self.calculate(weight, height, gender), error::WEIGHT_RANGE_KG)
+    }
+}
+
+impl FromStr for LeanMassFormula {
+    type Err = HealthError;
+
+    /// Parses `boer`, `james` and `hume`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "boer" => Ok(LeanMassFormula::Boer),
+            "james" => Ok(LeanMassFormula::James),
+            "hume" => Ok(LeanMassFormula::Hume),
+            _ => Err(HealthError::Unrecognized {
+                field: "lean mass formula",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Lean body mass by one formula, in kg.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeanMassEstimate {
+    pub formula: Formula,
+    pub lean_mass: f32,
+}
+
+/// Classification of the normalized FFMI (Kouri et al., 1995).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FfmiCategory {
+    BelowAverage,
+    Average,
+    AboveAverage,
+    Excellent,
+    Superior,
+    /// Above what is usually reached without anabolic steroids.
+    Exceptional,
+}
+
+impl Classification for FfmiCategory {
+    fn message(&self) -> Message {
+        Message::Ffmi(*self)
+    }
+
+    /// The FFMI describes muscularity, not a health risk.
+    fn risk(&self) -> Option<RiskLevel> {
+        None
+    }
+}
+
+/// Input of the body composition; weight in kg, height in m.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyCompositionData {
+    pub weight: f32,
+    pub height: f32,
+    pub gender: Gender,
+}
+
+impl BodyCompositionData {
+    /// Creates validated input.
+    pub fn new(weight: impl Into<Kilograms>, height: impl Into<Meters>, gender: Gender) -> Result<Self, HealthError> {
+        let data = BodyCompositionData { weight: weight.into().0, height: height.into().0, gender };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that weight (kg) and height (m) are plausible.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("weight", self.weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        Ok(())
+    }
+}
+
+/// Body composition derived from a body fat percentage.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyComposition {
+    pub pgc: f32,
+    /// Method that produced the PGC.
+    pub pgc_formula: Formula,
+    /// Fat mass, in kg.
+    pub fat_mass: f32,
+    /// Fat-free mass (weight − fat mass), in kg.
+    pub fat_free_mass: f32,
+    /// Lean body mass by the weight-and-height formulas that give a plausible value.
+    pub lean_body_mass: Vec<LeanMassEstimate>,
+    /// Fat-Free Mass Index, in kg/m².
+    pub ffmi: f32,
+    /// FFMI adjusted to a height of 1.80 m, in kg/m².
+    pub normalized_ffmi: f32,
+    pub ffmi_category: FfmiCategory,
+}
+
+impl BodyComposition {
+    /// Lean-mass TMB equation fed with this fat-free mass; other equations are returned unchanged.
+    pub fn bmr_formula(&self, name: BmrFormulaName) -> BmrFormula {
+        name.with_lean_mass(self.fat_free_mass)
+    }
+}
+
+/// Calculator for the body composition.
+pub struct BodyCompositionCalculator;
+
+impl BodyCompositionCalculator {
+    /// Validates the input and splits the weight by the PGC of any estimator.
+    pub fn from_estimate(data: &BodyCompositionData, estimate: &BodyFatAssessment) -> Result<BodyComposition, HealthError> {
+        Self::calculate(data, estimate.value, estimate.formula)
+    }
+
+    /// Validates the input and splits the weight by `pgc`, obtained with `pgc_formula`.
+    pub fn calculate(data: &BodyCompositionData, pgc: f32, pgc_formula: Formula) -> Result<BodyComposition, HealthError> {
+        data.validate()?;
+        let pgc = error::validate_measurement("pgc", pgc, (0.1, 99.9))?;
+        let fat_mass = data.weight * pgc / 100.0;
+        let fat_free_mass = data.weight - fat_mass;
+        let ffmi = error::ensure_finite("ffmi", fat_free_mass / (data.height * data.height))?;
+        let normalized_ffmi = ffmi + 6.1 * (FFMI_REFERENCE_HEIGHT_M - data.height);
+        let lean_body_mass = LeanMassFormula::ALL
+            .iter()
+            .filter_map(|formula| {
+                let lean_mass = formula.try_calculate(data.weight, data.height, data.gender).ok()?;
+                Some(LeanMassEstimate { formula: formula.formula(), lean_mass })
+            })
+            .collect();
+        Ok(BodyComposition {
+            pgc,
+            pgc_formula,
+            fat_mass,
+            fat_free_mass,
+            lean_body_mass,
+            ffmi,
+            normalized_ffmi,
+            ffmi_category: Self::classify_ffmi(normalized_ffmi, &data.gender),
+        })
+    }
+
+    /// Classifies a (normalized) FFMI by sex.
+    pub fn classify_ffmi(ffmi: f32, gender: &Gender) -> FfmiCategory {
+        let (average, above, excellent, superior, exceptional) = match gender {
+            Gender::Male => (18.0, 20.0, 22.0, 23.0, 26.0),
+            Gender::Female => (15.0, 17.0, 18.0, 19.0, 21.5),
+        };
+        match ffmi {
+            x if x < average => FfmiCategory::BelowAverage,
+            x if x < above => FfmiCategory::Average,
+            x if x < excellent => FfmiCategory::AboveAverage,
+            x if x < superior => FfmiCategory::Excellent,
+            x if x < exceptional => FfmiCategory::Superior,
+            _ => FfmiCategory::Exceptional,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn male() -> BodyCompositionData {
+        BodyCompositionData::new(70.0, 1.75, Gender::Male).unwrap()
+    }
+
+    #[test]
+    fn test_fat_and_fat_free_mass() {
+        let composition = BodyCompositionCalculator::calculate(&male(), 20.0, Formula::UsNavy).unwrap();
+        assert!((composition.fat_mass - 14.0).abs() < 1e-4);
+        assert!((composition.fat_free_mass - 56.0).abs() < 1e-4);
+        assert_eq!(composition.pgc_formula, Formula::UsNavy);
+    }
+
+    #[test]
+    fn test_ffmi() {
+        // 56 / 1.75² = 18.29; normalized + 6.1 × 0.05 = 18.59
+        let composition = BodyCompositionCalculator::calculate(&male(), 20.0, Formula::UsNavy).unwrap();
+        assert!((composition.ffmi - 18.286).abs() < 0.01);
+        assert!((composition.normalized_ffmi - 18.591).abs() < 0.01);
+        assert_eq!(composition.ffmi_category, FfmiCategory::Average);
+    }
+
+    #[test]
+    fn test_lean_mass_formulas() {
+        assert!((LeanMassFormula::Boer.calculate(70.0, 1.75, Gender::Male) - 56.015).abs() < 0.01);
+        assert!((LeanMassFormula::James.calculate(70.0, 1.75, Gender::Male) - 56.52).abs() < 0.01);
+        assert!((LeanMassFormula::Hume.calculate(70.0, 1.75, Gender::Male) - 52.81).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_leaves_out_negative_lean_mass() {
+        // James turns negative at high BMI: 1.1 × 225 − 128 × (225 / 160)² = −5.62
+        let data = BodyCompositionData::new(225.0, 1.60, Gender::Male).unwrap();
+        assert!(LeanMassFormula::James.try_calculate(225.0, 1.60, Gender::Male).is_err());
+        let composition = BodyCompositionCalculator::calculate(&data, 50.0, Formula::Deurenberg).unwrap();
+        let formulas: Vec<Formula> = composition.lean_body_mass.iter().map(|estimate| estimate.formula).collect();
+        assert_eq!(formulas, vec![Formula::Boer, Formula::Hume]);
+    }
+
+    #[test]
+    fn test_classify_ffmi() {
+        assert_eq!(BodyCompositionCalculator::classify_ffmi(17.0, &Gender::Male), FfmiCategory::BelowAverage);
+        assert_eq!(BodyCompositionCalculator::classify_ffmi(17.0, &Gender::Female), FfmiCategory::AboveAverage);
+        assert_eq!(BodyCompositionCalculator::classify_ffmi(27.0, &Gender::Male), FfmiCategory::Exceptional);
+    }
+
+    #[test]
+    fn test_feeds_lean_mass_bmr() {
+        let composition = BodyCompositionCalculator::calculate(&male(), 20.0, Formula::UsNavy).unwrap();
+        let formula = composition.bmr_formula(BmrFormulaName::KatchMcArdle);
+        assert_eq!(formula, BmrFormula::KatchMcArdle { lean_mass: composition.fat_free_mass });
+    }
+
+    #[test]
+    fn test_rejects_implausible_pgc() {
+        let result = BodyCompositionCalculator::calculate(&male(), 120.0, Formula::Deurenberg);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "pgc", .. })));
+    }
+}
@@ -0,0 +1,493 @@
+//! This module provides functionality to calculate and classify Body Fat Percentage (PGC)
+//! using the Deurenberg formula, with classification by sex and by sex+age.
+//! It follows SOLID principles for maintainability and extensibility.
+
+pub mod anthropometric; // CUN-BAE and Relative Fat Mass
+pub mod comparison; // Every applicable estimator side by side
+pub mod composition; // Fat mass, lean mass and FFMI
+pub mod navy; // US Navy circumference method
+pub mod skinfold; // Jackson-Pollock, Durnin-Womersley and Parrillo caliper methods
+
+use std::str::FromStr;
+
+use crate::assessment::{Assessment, Classification, Formula, ReferenceRange, RiskLevel, Unit};
+use crate::bmi::calculator::{BmiCalculator, BmiData};
+use crate::body_fat::skinfold::SkinfoldMethod;
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::units::{Kilograms, Meters};
+use crate::metabolism::tmb::Gender;
+
+/// Data structure for Body Fat calculation input.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyFatData {
+    pub weight: f32,
+    pub height: f32,
+    pub age: u32,
+    pub gender: Gender,
+}
+
+impl BodyFatData {
+    /// Creates validated body fat input, rejecting non-finite, non-positive or implausible values.
+    pub fn new(
+        weight: impl Into<Kilograms>,
+        height: impl Into<Meters>,
+        age: u32,
+        gender: Gender,
+    ) -> Result<Self, HealthError> {
+        let data = BodyFatData {
+            weight: weight.into().0,
+            height: height.into().0,
+            age,
+            gender,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that weight (kg), height (m) and age are plausible.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("weight", self.weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        error::validate_age(self.age)?;
+        Ok(())
+    }
+}
+
+/// Method used to estimate the PGC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BodyFatMethod {
+    /// Deurenberg BMI-based formula.
+    #[default]
+    Deurenberg,
+    /// US Navy circumference method.
+    UsNavy,
+    /// CUN-BAE: BMI, age and sex.
+    CunBae,
+    /// Relative Fat Mass: height-to-waist ratio and sex.
+    RelativeFatMass,
+    /// Skinfold caliper method.
+    Skinfold(SkinfoldMethod),
+}
+
+impl FromStr for BodyFatMethod {
+    type Err = HealthError;
+
+    /// Parses `deurenberg`, `navy` (or `us-navy`), `cun-bae`, `rfm` and the skinfold method names.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "deurenberg" | "bmi" | "imc" => Ok(BodyFatMethod::Deurenberg),
+            "navy" | "usnavy" | "marinha" => Ok(BodyFatMethod::UsNavy),
+            "cunbae" => Ok(BodyFatMethod::CunBae),
+            "rfm" | "relativefatmass" | "mgr" => Ok(BodyFatMethod::RelativeFatMass),
+            _ => input.parse().map(BodyFatMethod::Skinfold),
+        }
+    }
+}
+
+/// Enum for general body fat classification by sex.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BodyFatSexCategory {
+    Essential,
+    Athlete,
+    Fitness,
+    Acceptable,
+    Obesity,
+}
+
+impl Classification for BodyFatSexCategory {
+    fn message(&self) -> Message {
+        Message::BodyFatBySex(*self)
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            BodyFatSexCategory::Essential => RiskLevel::Moderate,
+            BodyFatSexCategory::Athlete | BodyFatSexCategory::Fitness => RiskLevel::Low,
+            BodyFatSexCategory::Acceptable => RiskLevel::Moderate,
+            BodyFatSexCategory::Obesity => RiskLevel::High,
+        })
+    }
+}
+
+/// Enum for body fat classification by sex and age.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BodyFatAgeCategory {
+    Low,
+    Normal,
+    High,
+    VeryHigh,
+}
+
+impl Classification for BodyFatAgeCategory {
+    fn message(&self) -> Message {
+        Message::BodyFatByAge(*self)
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            BodyFatAgeCategory::Low => RiskLevel::Moderate,
+            BodyFatAgeCategory::Normal => RiskLevel::Low,
+            BodyFatAgeCategory::High => RiskLevel::High,
+            BodyFatAgeCategory::VeryHigh => RiskLevel::VeryHigh,
+        })
+    }
+}
+
+/// Body fat classification by sex and by sex+age.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyFatClassification {
+    pub by_sex: BodyFatSexCategory,
+    /// `None` under 20, where the adult age-group bands do not apply.
+    pub by_age: Option<BodyFatAgeCategory>,
+}
+
+impl Classification for BodyFatClassification {
+    fn message(&self) -> Message {
+        self.by_age.map_or(self.by_sex.message(), |by_age| by_age.message())
+    }
+
+    /// The age-adjusted category drives the risk, when there is one.
+    fn risk(&self) -> Option<RiskLevel> {
+        self.by_age.map_or(self.by_sex.risk(), |by_age| by_age.risk())
+    }
+}
+
+/// Typed result of a body fat assessment.
+pub type BodyFatAssessment = Assessment<BodyFatClassification>;
+
+/// Main calculator for Body Fat Percentage.
+pub struct BodyFatCalculator;
+
+impl BodyFatCalculator {
+    /// Calculates BMI using the existing BMI module.
+    pub fn calculate_bmi(data: &BodyFatData) -> f32 {
+        let bmi_data = BmiData { weight: data.weight, height: data.height };
+        BmiCalculator::calculate(&bmi_data)
+    }
+
+    /// Validates the input and calculates the BMI, never returning NaN or infinity.
+    pub fn try_calculate_bmi(data: &BodyFatData) -> Result<f32, HealthError> {
+        data.validate()?;
+        error::ensure_finite("bmi", Self::calculate_bmi(data))
+    }
+
+    /// Validates the input and calculates the PGC with the Deurenberg formula.
+    pub fn try_calculate(data: &BodyFatData) -> Result<f32, HealthError> {
+        let bmi = Self::try_calculate_bmi(data)?;
+        plausible_pgc(Self::calculate_pgc(bmi, data.age, &data.gender))
+    }
+
+    /// Validates the input and estimates lean body mass (kg) from the PGC.
+    pub fn try_lean_mass(data: &BodyFatData) -> Result<f32, HealthError> {
+        let pgc = Self::try_calculate(data)?;
+        error::ensure_finite("lean mass", data.weight * (1.0 - pgc / 100.0))
+    }
+
+    /// Calculates Body Fat Percentage using the Deurenberg formula.
+    /// sex: 1 for male, 0 for female
+    pub fn calculate_pgc(bmi: f32, age: u32, gender: &Gender) -> f32 {
+        let sex = match gender {
+            Gender::Male => 1.0,
+            Gender::Female => 0.0,
+        };
+        (1.20 * bmi) + (0.23 * age as f32) - (10.8 * sex) - 5.4
+    }
+
+    /// Classifies Body Fat Percentage by sex.
+    pub fn classify_by_sex(pgc: f32, gender: &Gender) -> BodyFatSexCategory {
+        match gender {
+            Gender::Male => {
+                match pgc {
+                    x if x < 6.0 => BodyFatSexCategory::Essential,
+                    x if x < 14.0 => BodyFatSexCategory::Athlete,
+                    x if x < 18.0 => BodyFatSexCategory::Fitness,
+                    x if x < 25.0 => BodyFatSexCategory::Acceptable,
+                    _ => BodyFatSexCategory::Obesity,
+                }
+            }
+            Gender::Female => {
+                match pgc {
+                    x if x < 14.0 => BodyFatSexCategory::Essential,
+                    x if x < 21.0 => BodyFatSexCategory::Athlete,
+                    x if x < 25.0 => BodyFatSexCategory::Fitness,
+                    x if x < 32.0 => BodyFatSexCategory::Acceptable,
+                    _ => BodyFatSexCategory::Obesity,
+                }
+            }
+        }
+    }
+
+    /// Validates the input, calculates and classifies the PGC as a typed result.
+    pub fn assess(data: &BodyFatData) -> Result<BodyFatAssessment, HealthError> {
+        let pgc = Self::try_calculate(data)?;
+        Ok(Self::assess_pgc(pgc, data.age, &data.gender, Formula::Deurenberg))
+    }
+
+    /// Classifies a PGC obtained by any method, so results are comparable.
+    /// Under 20 only the classification by sex applies and the range is unbounded.
+    pub fn assess_pgc(pgc: f32, age: u32, gender: &Gender, formula: Formula) -> BodyFatAssessment {
+        let classification = BodyFatClassification {
+            by_sex: Self::classify_by_sex(pgc, gender),
+            by_age: Self::classify_by_age(pgc, age, gender),
+        };
+        let range = match Self::age_thresholds(age, gender) {
+            // The normal band includes its upper threshold
+            Some((low, normal, _, _)) => ReferenceRange::new(Some(low), Some(normal)).with_inclusive_max(),
+            None => ReferenceRange::new(None, None),
+        };
+        Assessment::new(pgc, Unit::Percent, classification, range, formula)
+    }
+
+    /// Classifies Body Fat Percentage by sex and age; `None` under 20.
+    pub fn classify_by_age(pgc: f32, age: u32, gender: &Gender) -> Option<BodyFatAgeCategory> {
+        let (low, normal, high, _very_high) = Self::age_thresholds(age, gender)?;
+        // Classify based on the calculated PGC and the defined ranges
+        Some(if pgc < low {
+            BodyFatAgeCategory::Low
+        } else if pgc <= normal {
+            BodyFatAgeCategory::Normal
+        } else if pgc <= high {
+            BodyFatAgeCategory::High
+        } else {
+            BodyFatAgeCategory::VeryHigh
+        })
+    }
+
+    /// PGC thresholds (low, normal, high, very high) for the sex and adult age group.
+    fn age_thresholds(age: u32, gender: &Gender) -> Option<(f32, f32, f32, f32)> {
+        let thresholds = match gender {
+            Gender::Male => match age {
+                20..=29 => (7.0, 19.0, 24.0, 25.0),
+                30..=39 => (8.0, 20.0, 25.0, 26.0),
+                40..=49 => (10.0, 22.0, 27.0, 28.0),
+                50..=59 => (11.0, 23.0, 28.0, 29.0),
+                60.. => (13.0, 25.0, 30.0, 31.0),
+                _ => return None,
+            },
+            Gender::Female => match age {
+                20..=29 => (16.0, 27.0, 32.0, 33.0),
+                30..=39 => (17.0, 28.0, 33.0, 34.0),
+                40..=49 => (18.0, 29.0, 34.0, 35.0),
+                50..=59 => (19.0, 30.0, 35.0, 36.0),
+                60.. => (20.0, 31.0, 36.0, 37.0),
+                _ => return None,
+            },
+        };
+        Some(thresholds)
+    }
+
+    /// Returns a string with the result for the user.
+    #[allow(unused_variables)]
+    pub fn evaluation_result(
+        pgc: f32,
+        gender: &Gender,
+        age: u32,
+        sex_category: &BodyFatSexCategory,
+        age_category: &BodyFatAgeCategory,
+    ) -> String {
+        let sex_str = match gender {
+            Gender::Male => "male",
+            Gender::Female => "female",
+        };
+        let sex_class = sex_category.label();
+        let age_class = age_category.label();
+        format!(
+            "Your Body Fat Percentage (PGC), sex [{}] is: {}\n\
+             Your PGC for sex [{}] and age group [{}] (OMC Standard) is: {}",
+            sex_str, sex_class, sex_str, age, age_class
+        )
+    }
+
+    /// Evaluates the body fat classification based on the BMI and returns a result message.
+    pub fn evaluate(bmi: f32, gender: &Gender) -> String {
+        let (low, normal, high, _very_high) = match gender {
+            Gender::Male => (18.5, 25.0, 30.0, 35.0),
+            Gender::Female => (18.5, 25.0, 30.0, 35.0),
+        };
+
+        if bmi < low {
+            format!("BMI: {:.2}\nCondition: Underweight", bmi)
+        } else if bmi < normal {
+            format!("BMI: {:.2}\nCondition: Normal weight", bmi)
+        } else if bmi < high {
+            format!("BMI: {:.2}\nCondition: Overweight", bmi)
+        } else {
+            format!("BMI: {:.2}\nCondition: Obese", bmi)
+        }
+    }
+} 
+
+// ... existing code ...
+
+/// Rejects estimator results that are not a finite percentage between 0 and 100.
+pub(crate) fn plausible_pgc(pgc: f32) -> Result<f32, HealthError> {
+    let pgc = error::ensure_finite("pgc", pgc)?;
+    if !(0.0..100.0).contains(&pgc) {
+        return Err(HealthError::InvalidResult { calculation: "pgc" });
+    }
+    Ok(pgc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_male() {
+        let data = BodyFatData {
+            weight: 70.0,
+            height: 1.75,
+            age: 30,
+            gender: Gender::Male,
+        };
+        let bmi = BodyFatCalculator::calculate_bmi(&data);
+        assert!((bmi - 22.86).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_calculate_female() {
+        let data = BodyFatData {
+            weight: 60.0,
+            height: 1.65,
+            age: 30,
+            gender: Gender::Female,
+        };
+        let bmi = BodyFatCalculator::calculate_bmi(&data);
+        assert!((bmi - 22.04).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_evaluate_male_underweight() {
+        let bmi = 17.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Male);
+        assert!(result.contains("Underweight"));
+    }
+
+    #[test]
+    fn test_evaluate_male_normal() {
+        let bmi = 22.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Male);
+        assert!(result.contains("Normal weight"));
+    }
+
+    #[test]
+    fn test_evaluate_male_overweight() {
+        let bmi = 27.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Male);
+        assert!(result.contains("Overweight"));
+    }
+
+    #[test]
+    fn test_evaluate_male_obese() {
+        let bmi = 32.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Male);
+        assert!(result.contains("Obese"));
+    }
+
+    #[test]
+    fn test_evaluate_female_underweight() {
+        let bmi = 17.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Female);
+        assert!(result.contains("Underweight"));
+    }
+
+    #[test]
+    fn test_evaluate_female_normal() {
+        let bmi = 22.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Female);
+        assert!(result.contains("Normal weight"));
+    }
+
+    #[test]
+    fn test_evaluate_female_overweight() {
+        let bmi = 27.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Female);
+        assert!(result.contains("Overweight"));
+    }
+
+    #[test]
+    fn test_evaluate_female_obese() {
+        let bmi = 32.0;
+        let result = BodyFatCalculator::evaluate(bmi, &Gender::Female);
+        assert!(result.contains("Obese"));
+    }
+
+    #[test]
+    fn test_new_rejects_infinite_weight() {
+        let result = BodyFatData::new(f32::INFINITY, 1.75, 30, Gender::Male);
+        assert_eq!(result.err(), Some(HealthError::NotFinite { field: "weight" }));
+    }
+
+    #[test]
+    fn test_try_calculate_rejects_zero_height() {
+        let data = BodyFatData {
+            weight: 70.0,
+            height: 0.0,
+            age: 30,
+            gender: Gender::Male,
+        };
+        let result = BodyFatCalculator::try_calculate(&data);
+        assert_eq!(result, Err(HealthError::Zero { field: "height" }));
+    }
+
+    #[test]
+    fn test_new_with_imperial_units() {
+        use crate::units::{FeetInches, Pounds};
+        let imperial = BodyFatData::new(Pounds(160.0), FeetInches::new(5, 10.0), 30, Gender::Male).unwrap();
+        let metric = BodyFatData::new(72.5748, 1.778, 30, Gender::Male).unwrap();
+        let pgc = BodyFatCalculator::try_calculate(&imperial).unwrap();
+        assert!((pgc - BodyFatCalculator::try_calculate(&metric).unwrap()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_assess() {
+        let data = BodyFatData::new(70.0, 1.75, 30, Gender::Male).unwrap();
+        let assessment = BodyFatCalculator::assess(&data).unwrap();
+        assert!((assessment.value - 18.13).abs() < 0.01);
+        assert_eq!(assessment.unit, Unit::Percent);
+        assert_eq!(assessment.category.by_sex, BodyFatSexCategory::Acceptable);
+        assert_eq!(assessment.category.by_age, Some(BodyFatAgeCategory::Normal));
+        assert_eq!(assessment.reference_range, ReferenceRange::new(Some(8.0), Some(20.0)).with_inclusive_max());
+        assert_eq!(assessment.formula, Formula::Deurenberg);
+        assert_eq!(assessment.risk, Some(RiskLevel::Low));
+    }
+
+    #[test]
+    fn test_assess_under_20_has_no_age_bands() {
+        let assessment = BodyFatCalculator::assess_pgc(15.0, 16, &Gender::Male, Formula::Deurenberg);
+        assert_eq!(assessment.category.by_age, None);
+        assert_eq!(assessment.reference_range, ReferenceRange::new(None, None));
+        assert_eq!(assessment.risk, Some(RiskLevel::Low));
+        let elderly = BodyFatCalculator::assess_pgc(15.0, 60, &Gender::Male, Formula::Deurenberg);
+        assert_eq!(elderly.category.by_age, Some(BodyFatAgeCategory::Normal));
+    }
+
+    #[test]
+    fn test_parse_method() {
+        assert_eq!("US-Navy".parse::<BodyFatMethod>(), Ok(BodyFatMethod::UsNavy));
+        assert_eq!("deurenberg".parse::<BodyFatMethod>(), Ok(BodyFatMethod::Deurenberg));
+        assert_eq!("jp3".parse::<BodyFatMethod>(), Ok(BodyFatMethod::Skinfold(SkinfoldMethod::JacksonPollock3)));
+        assert_eq!("CUN-BAE".parse::<BodyFatMethod>(), Ok(BodyFatMethod::CunBae));
+        assert!("calipers".parse::<BodyFatMethod>().is_err());
+    }
+
+    #[test]
+    fn test_try_calculate_valid_data() {
+        let data = BodyFatData::new(70.0, 1.75, 30, Gender::Male).unwrap();
+        let pgc = BodyFatCalculator::try_calculate(&data).unwrap();
+        // 1.20 * 22.86 + 0.23 * 30 - 10.8 - 5.4 = 18.13
+        assert!((pgc - 18.13).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_try_calculate_rejects_implausible_pgc() {
+        let data = BodyFatData::new(20.0, 1.80, 20, Gender::Male).unwrap();
+        assert_eq!(BodyFatCalculator::try_calculate(&data), Err(HealthError::InvalidResult { calculation: "pgc" }));
+    }
+}
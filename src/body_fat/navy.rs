@@ -0,0 +1,145 @@
+//! US Navy circumference method for Body Fat Percentage (PGC).
+//!
+//! Uses height and the neck and waist circumferences (plus the hip for
+//! women) instead of the BMI, so it does not mistake muscle for fat the way
+//! the Deurenberg formula does.
+
+use crate::assessment::Formula;
+use crate::body_fat::{plausible_pgc, BodyFatAssessment, BodyFatCalculator};
+use crate::error::{self, HealthError};
+use crate::metabolism::tmb::Gender;
+use crate::units::{Centimeters, Meters};
+
+/// Input of the US Navy method; circumferences in cm, height in m.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NavyBodyFatData {
+    pub height: f32,
+    pub neck: f32,
+    pub waist: f32,
+    /// Required for women, ignored for men.
+    pub hip: Option<f32>,
+    pub age: u32,
+    pub gender: Gender,
+}
+
+impl NavyBodyFatData {
+    /// Creates validated input.
+    pub fn new(
+        height: impl Into<Meters>,
+        neck: impl Into<Centimeters>,
+        waist: impl Into<Centimeters>,
+        hip: Option<Centimeters>,
+        age: u32,
+        gender: Gender,
+    ) -> Result<Self, HealthError> {
+        let data = NavyBodyFatData {
+            height: height.into().0,
+            neck: neck.into().0,
+            waist: waist.into().0,
+            hip: hip.map(|hip| hip.0),
+            age,
+            gender,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks the measurements, that the hip is given for women and that the
+    /// waist is larger than the neck (the logarithm needs a positive difference).
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        error::validate_measurement("neck", self.neck, error::CIRCUMFERENCE_RANGE_CM)?;
+        error::validate_measurement("waist", self.waist, error::CIRCUMFERENCE_RANGE_CM)?;
+        if self.waist <= self.neck {
+            return Err(HealthError::Implausible {
+                field: "waist",
+                value: self.waist,
+                min: self.neck,
+                max: error::CIRCUMFERENCE_RANGE_CM.1,
+            });
+        }
+        match (self.gender, self.hip) {
+            (_, Some(hip)) => {
+                error::validate_measurement("hip", hip, error::CIRCUMFERENCE_RANGE_CM)?;
+            }
+            (Gender::Female, None) => return Err(HealthError::Missing { field: "hip" }),
+            (Gender::Male, None) => {}
+        }
+        error::validate_age(self.age)?;
+        Ok(())
+    }
+}
+
+/// Calculator for the US Navy method (Hodgdon & Beckett, 1984).
+pub struct NavyBodyFatCalculator;
+
+impl NavyBodyFatCalculator {
+    /// Calculates the PGC with the metric form of the equations (lengths in cm):
+    ///
+    /// - men: 495 / (1.0324 − 0.19077 × log10(waist − neck) + 0.15456 × log10(height)) − 450
+    /// - women: 495 / (1.29579 − 0.35004 × log10(waist + hip − neck) + 0.22100 × log10(height)) − 450
+    pub fn calculate(data: &NavyBodyFatData) -> f32 {
+        let height_cm = Centimeters::from(Meters(data.height)).0;
+        let density = match data.gender {
+            Gender::Male => 1.0324 - 0.19077 * (data.waist - data.neck).log10() + 0.15456 * height_cm.log10(),
+            Gender::Female => {
+                let hip = data.hip.unwrap_or(f32::NAN);
+                1.29579 - 0.35004 * (data.waist + hip - data.neck).log10() + 0.22100 * height_cm.log10()
+            }
+        };
+        495.0 / density - 450.0
+    }
+
+    /// Validates the input and calculates the PGC, rejecting results that are
+    /// not a plausible percentage.
+    pub fn try_calculate(data: &NavyBodyFatData) -> Result<f32, HealthError> {
+        data.validate()?;
+        plausible_pgc(Self::calculate(data))
+    }
+
+    /// Validates the input and classifies the PGC with the same sex and age
+    /// tables as the Deurenberg estimate.
+    pub fn assess(data: &NavyBodyFatData) -> Result<BodyFatAssessment, HealthError> {
+        let pgc = Self::try_calculate(data)?;
+        Ok(BodyFatCalculator::assess_pgc(pgc, data.age, &data.gender, Formula::UsNavy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::body_fat::{BodyFatAgeCategory, BodyFatSexCategory};
+
+    #[test]
+    fn test_calculate_male() {
+        let data = NavyBodyFatData::new(1.75, 38.0, 85.0, None, 30, Gender::Male).unwrap();
+        assert!((NavyBodyFatCalculator::calculate(&data) - 16.94).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_calculate_female() {
+        let data = NavyBodyFatData::new(1.65, 32.0, 75.0, Some(Centimeters(95.0)), 30, Gender::Female).unwrap();
+        assert!((NavyBodyFatCalculator::calculate(&data) - 27.43).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_female_requires_hip() {
+        let result = NavyBodyFatData::new(1.65, 32.0, 75.0, None, 30, Gender::Female);
+        assert_eq!(result.err(), Some(HealthError::Missing { field: "hip" }));
+    }
+
+    #[test]
+    fn test_waist_must_exceed_neck() {
+        let result = NavyBodyFatData::new(1.75, 40.0, 40.0, None, 30, Gender::Male);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "waist", .. })));
+    }
+
+    #[test]
+    fn test_assess_uses_shared_classification() {
+        let data = NavyBodyFatData::new(1.75, 38.0, 85.0, None, 30, Gender::Male).unwrap();
+        let assessment = NavyBodyFatCalculator::assess(&data).unwrap();
+        assert_eq!(assessment.formula, Formula::UsNavy);
+        assert_eq!(assessment.category.by_sex, BodyFatSexCategory::Fitness);
+        assert_eq!(assessment.category.by_age, Some(BodyFatAgeCategory::Normal));
+    }
+}
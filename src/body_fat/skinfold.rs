@@ -0,0 +1,406 @@
+//! Skinfold caliper methods for Body Fat Percentage (PGC).
+//!
+//! Jackson-Pollock and Durnin-Womersley estimate body density from the sum
+//! of skinfolds; Siri or Brozek then convert the density to a percentage.
+//! Parrillo's nine-site method gives the percentage directly from the sum
+//! and the body weight.
+
+use std::str::FromStr;
+
+use crate::assessment::Formula;
+use crate::body_fat::{plausible_pgc, BodyFatAssessment, BodyFatCalculator};
+use crate::error::{self, HealthError};
+use crate::metabolism::tmb::Gender;
+use crate::units::{Kilograms, Pounds};
+
+/// Measurement site of a skinfold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkinfoldSite {
+    Chest,
+    Midaxillary,
+    Abdomen,
+    Suprailiac,
+    Thigh,
+    Triceps,
+    Biceps,
+    Subscapular,
+    LowerBack,
+    Calf,
+}
+
+impl SkinfoldSite {
+    /// Every site, in the order they are usually measured.
+    pub const ALL: [SkinfoldSite; 10] = [
+        SkinfoldSite::Chest,
+        SkinfoldSite::Midaxillary,
+        SkinfoldSite::Abdomen,
+        SkinfoldSite::Suprailiac,
+        SkinfoldSite::Thigh,
+        SkinfoldSite::Triceps,
+        SkinfoldSite::Biceps,
+        SkinfoldSite::Subscapular,
+        SkinfoldSite::LowerBack,
+        SkinfoldSite::Calf,
+    ];
+
+    /// Name of the site's measurement, as used in error messages.
+    pub fn field(&self) -> &'static str {
+        match self {
+            SkinfoldSite::Chest => "chest skinfold",
+            SkinfoldSite::Midaxillary => "midaxillary skinfold",
+            SkinfoldSite::Abdomen => "abdominal skinfold",
+            SkinfoldSite::Suprailiac => "suprailiac skinfold",
+            SkinfoldSite::Thigh => "thigh skinfold",
+            SkinfoldSite::Triceps => "triceps skinfold",
+            SkinfoldSite::Biceps => "biceps skinfold",
+            SkinfoldSite::Subscapular => "subscapular skinfold",
+            SkinfoldSite::LowerBack => "lower back skinfold",
+            SkinfoldSite::Calf => "calf skinfold",
+        }
+    }
+}
+
+/// Skinfold thicknesses in millimeters; sites not measured are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Skinfolds {
+    pub chest: Option<f32>,
+    pub midaxillary: Option<f32>,
+    pub abdomen: Option<f32>,
+    pub suprailiac: Option<f32>,
+    pub thigh: Option<f32>,
+    pub triceps: Option<f32>,
+    pub biceps: Option<f32>,
+    pub subscapular: Option<f32>,
+    pub lower_back: Option<f32>,
+    pub calf: Option<f32>,
+}
+
+impl Skinfolds {
+    /// Returns a copy with the site set to `millimeters`.
+    pub fn with(mut self, site: SkinfoldSite, millimeters: f32) -> Self {
+        *self.site_mut(site) = Some(millimeters);
+        self
+    }
+
+    /// Thickness at the site, if measured.
+    pub fn get(&self, site: SkinfoldSite) -> Option<f32> {
+        match site {
+            SkinfoldSite::Chest => self.chest,
+            SkinfoldSite::Midaxillary => self.midaxillary,
+            SkinfoldSite::Abdomen => self.abdomen,
+            SkinfoldSite::Suprailiac => self.suprailiac,
+            SkinfoldSite::Thigh => self.thigh,
+            SkinfoldSite::Triceps => self.triceps,
+            SkinfoldSite::Biceps => self.biceps,
+            SkinfoldSite::Subscapular => self.subscapular,
+            SkinfoldSite::LowerBack => self.lower_back,
+            SkinfoldSite::Calf => self.calf,
+        }
+    }
+
+    fn site_mut(&mut self, site: SkinfoldSite) -> &mut Option<f32> {
+        match site {
+            SkinfoldSite::Chest => &mut self.chest,
+            SkinfoldSite::Midaxillary => &mut self.midaxillary,
+            SkinfoldSite::Abdomen => &mut self.abdomen,
+            SkinfoldSite::Suprailiac => &mut self.suprailiac,
+            SkinfoldSite::Thigh => &mut self.thigh,
+            SkinfoldSite::Triceps => &mut self.triceps,
+            SkinfoldSite::Biceps => &mut self.biceps,
+            SkinfoldSite::Subscapular => &mut self.subscapular,
+            SkinfoldSite::LowerBack => &mut self.lower_back,
+            SkinfoldSite::Calf => &mut self.calf,
+        }
+    }
+
+    /// Checks every measured site against the plausible caliper range.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        for site in SkinfoldSite::ALL {
+            if let Some(millimeters) = self.get(site) {
+                error::validate_measurement(site.field(), millimeters, error::SKINFOLD_RANGE_MM)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sum of the given sites, failing on the first one not measured.
+    pub fn sum(&self, sites: &[SkinfoldSite]) -> Result<f32, HealthError> {
+        sites.iter().try_fold(0.0, |sum, site| {
+            self.get(*site).map(|mm| sum + mm).ok_or(HealthError::Missing { field: site.field() })
+        })
+    }
+}
+
+/// Skinfold equation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SkinfoldMethod {
+    /// Jackson-Pollock 3-site: chest, abdomen, thigh (men); triceps, suprailiac, thigh (women).
+    JacksonPollock3,
+    /// Jackson-Pollock 7-site: chest, midaxillary, triceps, subscapular, abdomen, suprailiac, thigh.
+    JacksonPollock7,
+    /// Durnin-Womersley 4-site: biceps, triceps, subscapular, suprailiac.
+    DurninWomersley,
+    /// Parrillo 9-site: 27 × sum / weight (lb).
+    Parrillo,
+}
+
+impl SkinfoldMethod {
+    /// Sites the method needs for the given sex.
+    pub fn sites(&self, gender: Gender) -> &'static [SkinfoldSite] {
+        use SkinfoldSite::*;
+        match (self, gender) {
+            (SkinfoldMethod::JacksonPollock3, Gender::Male) => &[Chest, Abdomen, Thigh],
+            (SkinfoldMethod::JacksonPollock3, Gender::Female) => &[Triceps, Suprailiac, Thigh],
+            (SkinfoldMethod::JacksonPollock7, _) => &[Chest, Midaxillary, Triceps, Subscapular, Abdomen, Suprailiac, Thigh],
+            (SkinfoldMethod::DurninWomersley, _) => &[Biceps, Triceps, Subscapular, Suprailiac],
+            (SkinfoldMethod::Parrillo, _) => {
+                &[Chest, Abdomen, Thigh, Biceps, Triceps, Subscapular, Suprailiac, LowerBack, Calf]
+            }
+        }
+    }
+
+    /// Formula reported in the assessment.
+    pub fn formula(&self) -> Formula {
+        match self {
+            SkinfoldMethod::JacksonPollock3 => Formula::JacksonPollock3,
+            SkinfoldMethod::JacksonPollock7 => Formula::JacksonPollock7,
+            SkinfoldMethod::DurninWomersley => Formula::DurninWomersley,
+            SkinfoldMethod::Parrillo => Formula::Parrillo,
+        }
+    }
+}
+
+impl FromStr for SkinfoldMethod {
+    type Err = HealthError;
+
+    /// Parses `jp3`, `jp7`, `durnin-womersley` (or `dw`) and `parrillo`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "jp3" | "jacksonpollock3" | "jacksonpollock" => Ok(SkinfoldMethod::JacksonPollock3),
+            "jp7" | "jacksonpollock7" => Ok(SkinfoldMethod::JacksonPollock7),
+            "dw" | "durninwomersley" | "durnin" => Ok(SkinfoldMethod::DurninWomersley),
+            "parrillo" => Ok(SkinfoldMethod::Parrillo),
+            _ => Err(HealthError::Unrecognized {
+                field: "method",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Equation converting body density (g/cm³) to PGC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DensityEquation {
+    /// Siri (1961): 495 / density − 450.
+    #[default]
+    Siri,
+    /// Brozek (1963): 457 / density − 414.2.
+    Brozek,
+}
+
+impl DensityEquation {
+    /// Converts a body density to PGC.
+    pub fn pgc(&self, density: f32) -> f32 {
+        match self {
+            DensityEquation::Siri => 495.0 / density - 450.0,
+            DensityEquation::Brozek => 457.0 / density - 414.2,
+        }
+    }
+}
+
+impl FromStr for DensityEquation {
+    type Err = HealthError;
+
+    /// Parses `siri` and `brozek`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "siri" => Ok(DensityEquation::Siri),
+            "brozek" | "brožek" => Ok(DensityEquation::Brozek),
+            _ => Err(HealthError::Unrecognized {
+                field: "density equation",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Input of the skinfold methods.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkinfoldData {
+    pub skinfolds: Skinfolds,
+    pub weight: f32,
+    pub age: u32,
+    pub gender: Gender,
+}
+
+impl SkinfoldData {
+    /// Creates validated input; every measured site is checked, whichever method is used.
+    pub fn new(skinfolds: Skinfolds, weight: impl Into<Kilograms>, age: u32, gender: Gender) -> Result<Self, HealthError> {
+        let data = SkinfoldData { skinfolds, weight: weight.into().0, age, gender };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks the skinfolds (mm), weight (kg) and age.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        self.skinfolds.validate()?;
+        error::validate_measurement("weight", self.weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_age(self.age)?;
+        Ok(())
+    }
+}
+
+/// Calculator for the skinfold methods.
+pub struct SkinfoldCalculator;
+
+impl SkinfoldCalculator {
+    /// Body density (g/cm³) from the method's sites; `None` for Parrillo,
+    /// which does not go through density.
+    pub fn body_density(data: &SkinfoldData, method: SkinfoldMethod) -> Result<Option<f32>, HealthError> {
+        let sum = data.skinfolds.sum(method.sites(data.gender))?;
+        let age = data.age as f32;
+        let density = match (method, data.gender) {
+            (SkinfoldMethod::JacksonPollock3, Gender::Male) => {
+                1.10938 - 0.0008267 * sum + 0.0000016 * sum * sum - 0.0002574 * age
+            }
+            (SkinfoldMethod::JacksonPollock3, Gender::Female) => {
+                1.0994921 - 0.0009929 * sum + 0.0000023 * sum * sum - 0.0001392 * age
+            }
+            (SkinfoldMethod::JacksonPollock7, Gender::Male) => {
+                1.112 - 0.00043499 * sum + 0.00000055 * sum * sum - 0.00028826 * age
+            }
+            (SkinfoldMethod::JacksonPollock7, Gender::Female) => {
+                1.097 - 0.00046971 * sum + 0.00000056 * sum * sum - 0.00012828 * age
+            }
+            (SkinfoldMethod::DurninWomersley, gender) => {
+                let (intercept, slope) = Self::durnin_womersley_coefficients(data.age, gender);
+                intercept - slope * sum.log10()
+            }
+            (SkinfoldMethod::Parrillo, _) => return Ok(None),
+        };
+        Ok(Some(density))
+    }
+
+    /// Validates the input and calculates the PGC, rejecting results that are
+    /// not a plausible percentage.
+    pub fn try_calculate(
+        data: &SkinfoldData,
+        method: SkinfoldMethod,
+        equation: DensityEquation,
+    ) -> Result<f32, HealthError> {
+        data.validate()?;
+        let pgc = match Self::body_density(data, method)? {
+            Some(density) => equation.pgc(density),
+            None => {
+                let sum = data.skinfolds.sum(method.sites(data.gender))?;
+                27.0 * sum / Pounds::from(Kilograms(data.weight)).0
+            }
+        };
+        plausible_pgc(pgc)
+    }
+
+    /// Validates the input and classifies the PGC with the same sex and age
+    /// tables as the Deurenberg estimate.
+    pub fn assess(
+        data: &SkinfoldData,
+        method: SkinfoldMethod,
+        equation: DensityEquation,
+    ) -> Result<BodyFatAssessment, HealthError> {
+        let pgc = Self::try_calculate(data, method, equation)?;
+        Ok(BodyFatCalculator::assess_pgc(pgc, data.age, &data.gender, method.formula()))
+    }
+
+    /// Durnin-Womersley (1974) intercept and slope for the sex and age group.
+    fn durnin_womersley_coefficients(age: u32, gender: Gender) -> (f32, f32) {
+        match gender {
+            Gender::Male => match age {
+                0..=16 => (1.1533, 0.0643),
+                17..=19 => (1.1620, 0.0630),
+                20..=29 => (1.1631, 0.0632),
+                30..=39 => (1.1422, 0.0544),
+                40..=49 => (1.1620, 0.0700),
+                _ => (1.1715, 0.0779), // 50+
+            },
+            Gender::Female => match age {
+                0..=16 => (1.1369, 0.0598),
+                17..=19 => (1.1549, 0.0678),
+                20..=29 => (1.1599, 0.0717),
+                30..=39 => (1.1423, 0.0632),
+                40..=49 => (1.1333, 0.0612),
+                _ => (1.1339, 0.0645), // 50+
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_sites(millimeters: f32) -> Skinfolds {
+        SkinfoldSite::ALL.iter().fold(Skinfolds::default(), |folds, site| folds.with(*site, millimeters))
+    }
+
+    #[test]
+    fn test_jackson_pollock_3_male() {
+        // Sum 60 mm, age 30: density 1.10938 − 0.049602 + 0.00576 − 0.007722 = 1.057816
+        let data = SkinfoldData::new(all_sites(20.0), 80.0, 30, Gender::Male).unwrap();
+        let density = SkinfoldCalculator::body_density(&data, SkinfoldMethod::JacksonPollock3).unwrap().unwrap();
+        assert!((density - 1.057816).abs() < 1e-5);
+        let pgc = SkinfoldCalculator::try_calculate(&data, SkinfoldMethod::JacksonPollock3, DensityEquation::Siri).unwrap();
+        assert!((pgc - 17.95).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_brozek_reads_higher_than_siri_for_lean_people() {
+        let data = SkinfoldData::new(all_sites(8.0), 75.0, 25, Gender::Male).unwrap();
+        let siri = SkinfoldCalculator::try_calculate(&data, SkinfoldMethod::JacksonPollock7, DensityEquation::Siri).unwrap();
+        let brozek =
+            SkinfoldCalculator::try_calculate(&data, SkinfoldMethod::JacksonPollock7, DensityEquation::Brozek).unwrap();
+        // The equations cross at a density of about 1.0615 g/cm³ (16% fat)
+        assert!(siri > 0.0 && brozek > siri);
+    }
+
+    #[test]
+    fn test_durnin_womersley_female() {
+        // Sum 60 mm, age 25: density 1.1599 − 0.0717 × log10(60) = 1.03241
+        let data = SkinfoldData::new(all_sites(15.0), 60.0, 25, Gender::Female).unwrap();
+        let density = SkinfoldCalculator::body_density(&data, SkinfoldMethod::DurninWomersley).unwrap().unwrap();
+        assert!((density - 1.03241).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_parrillo() {
+        // 27 × 90 / 176.37 lb = 13.78
+        let data = SkinfoldData::new(all_sites(10.0), 80.0, 30, Gender::Male).unwrap();
+        let pgc = SkinfoldCalculator::try_calculate(&data, SkinfoldMethod::Parrillo, DensityEquation::Siri).unwrap();
+        assert!((pgc - 13.78).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_missing_site() {
+        let skinfolds = Skinfolds::default().with(SkinfoldSite::Chest, 10.0).with(SkinfoldSite::Abdomen, 20.0);
+        let data = SkinfoldData::new(skinfolds, 80.0, 30, Gender::Male).unwrap();
+        let result = SkinfoldCalculator::try_calculate(&data, SkinfoldMethod::JacksonPollock3, DensityEquation::Siri);
+        assert_eq!(result, Err(HealthError::Missing { field: "thigh skinfold" }));
+    }
+
+    #[test]
+    fn test_validates_each_site() {
+        let skinfolds = Skinfolds::default().with(SkinfoldSite::Triceps, 150.0);
+        let result = SkinfoldData::new(skinfolds, 80.0, 30, Gender::Male);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "triceps skinfold", .. })));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("JP7".parse::<SkinfoldMethod>(), Ok(SkinfoldMethod::JacksonPollock7));
+        assert_eq!("durnin-womersley".parse::<SkinfoldMethod>(), Ok(SkinfoldMethod::DurninWomersley));
+        assert_eq!("Brozek".parse::<DensityEquation>(), Ok(DensityEquation::Brozek));
+    }
+}
@@ -0,0 +1,176 @@
+//! Body surface area (BSA) in m², used to dose chemotherapy and other drugs.
+//!
+//! Takes the same weight and height as the BMI. The formulas agree within a
+//! few percent for adults of average build and diverge at the extremes.
+
+use std::str::FromStr;
+
+use crate::assessment::Formula;
+use crate::bmi::calculator::BmiData;
+use crate::error::{self, HealthError};
+use crate::units::{Centimeters, Meters};
+
+/// Body surface area equation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BsaFormula {
+    /// Mosteller (1987), the usual choice for dosing.
+    #[default]
+    Mosteller,
+    /// DuBois & DuBois (1916).
+    DuBois,
+    /// Haycock (1978), validated down to newborns.
+    Haycock,
+    /// Gehan & George (1970).
+    GehanGeorge,
+    /// Boyd (1935), with a weight-dependent exponent.
+    Boyd,
+}
+
+impl BsaFormula {
+    /// Every formula.
+    pub const ALL: [BsaFormula; 5] =
+        [BsaFormula::Mosteller, BsaFormula::DuBois, BsaFormula::Haycock, BsaFormula::GehanGeorge, BsaFormula::Boyd];
+
+    /// Formula reported in results.
+    pub fn formula(&self) -> Formula {
+        match self {
+            BsaFormula::Mosteller => Formula::Mosteller,
+            BsaFormula::DuBois => Formula::DuBois,
+            BsaFormula::Haycock => Formula::Haycock,
+            BsaFormula::GehanGeorge => Formula::GehanGeorge,
+            BsaFormula::Boyd => Formula::Boyd,
+        }
+    }
+
+    /// Body surface area in m², from weight (kg) and height (m).
+    pub fn calculate(&self, weight: f32, height: f32) -> f32 {
+        let height_cm = Centimeters::from(Meters(height)).0;
+        match self {
+            BsaFormula::Mosteller => (height_cm * weight / 3600.0).sqrt(),
+            BsaFormula::DuBois => 0.007184 * weight.powf(0.425) * height_cm.powf(0.725),
+            BsaFormula::Haycock => 0.024265 * weight.powf(0.5378) * height_cm.powf(0.3964),
+            BsaFormula::GehanGeorge => 0.0235 * weight.powf(0.51456) * height_cm.powf(0.42246),
+            BsaFormula::Boyd => {
+                let grams = weight * 1000.0;
+                0.0003207 * height_cm.powf(0.3) * grams.powf(0.7285 - 0.0188 * grams.log10())
+            }
+        }
+    }
+}
+
+impl FromStr for BsaFormula {
+    type Err = HealthError;
+
+    /// Parses `mosteller`, `dubois` (or `du-bois`), `haycock`, `gehan-george` (or `gehan`) and `boyd`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "mosteller" => Ok(BsaFormula::Mosteller),
+            "dubois" | "duboisdubois" => Ok(BsaFormula::DuBois),
+            "haycock" => Ok(BsaFormula::Haycock),
+            "gehangeorge" | "gehan" => Ok(BsaFormula::GehanGeorge),
+            "boyd" => Ok(BsaFormula::Boyd),
+            _ => Err(HealthError::Unrecognized {
+                field: "bsa formula",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Body surface area by one formula, in m².
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BsaEstimate {
+    pub formula: Formula,
+    pub bsa: f32,
+}
+
+/// Body surface area by one or more formulas.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BsaResult {
+    pub estimates: Vec<BsaEstimate>,
+}
+
+/// Calculator for the body surface area.
+pub struct BsaCalculator;
+
+impl BsaCalculator {
+    /// Body surface area in m² by `formula`.
+    pub fn calculate(data: &BmiData, formula: BsaFormula) -> f32 {
+        formula.calculate(data.weight, data.height)
+    }
+
+    /// Validates the input and calculates the BSA, never returning NaN or infinity.
+    pub fn try_calculate(data: &BmiData, formula: BsaFormula) -> Result<f32, HealthError> {
+        data.validate()?;
+        error::ensure_finite("bsa", Self::calculate(data, formula))
+    }
+
+    /// Validates the input and calculates the BSA by each of `formulas`, in order.
+    pub fn estimate(data: &BmiData, formulas: &[BsaFormula]) -> Result<BsaResult, HealthError> {
+        let estimates = formulas
+            .iter()
+            .map(|formula| {
+                Ok(BsaEstimate {
+                    formula: formula.formula(),
+                    bsa: Self::try_calculate(data, *formula)?,
+                })
+            })
+            .collect::<Result<_, HealthError>>()?;
+        Ok(BsaResult { estimates })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adult() -> BmiData {
+        BmiData::new(70.0, 1.75).unwrap()
+    }
+
+    #[test]
+    fn test_formulas() {
+        let expected = [
+            (BsaFormula::Mosteller, 1.845),
+            (BsaFormula::DuBois, 1.848),
+            (BsaFormula::Haycock, 1.847),
+            (BsaFormula::GehanGeorge, 1.854),
+            (BsaFormula::Boyd, 1.851),
+        ];
+        for (formula, bsa) in expected {
+            let value = BsaCalculator::try_calculate(&adult(), formula).unwrap();
+            assert!((value - bsa).abs() < 0.005, "{formula:?}: {value}");
+        }
+    }
+
+    #[test]
+    fn test_estimate_keeps_order() {
+        let result = BsaCalculator::estimate(&adult(), &BsaFormula::ALL).unwrap();
+        let formulas: Vec<Formula> = result.estimates.iter().map(|estimate| estimate.formula).collect();
+        assert_eq!(formulas, BsaFormula::ALL.map(|formula| formula.formula()));
+    }
+
+    #[test]
+    fn test_infant() {
+        // Haycock for a 3.5 kg, 50 cm newborn: about 0.22 m²
+        let data = BmiData::new(3.5, 0.5).unwrap();
+        assert!((BsaCalculator::calculate(&data, BsaFormula::Haycock) - 0.22).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("Du Bois".parse::<BsaFormula>(), Ok(BsaFormula::DuBois));
+        assert_eq!("gehan-george".parse::<BsaFormula>(), Ok(BsaFormula::GehanGeorge));
+        assert!("martian".parse::<BsaFormula>().is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        let data = BmiData { weight: f32::NAN, height: 1.75 };
+        assert!(matches!(BsaCalculator::try_calculate(&data, BsaFormula::Mosteller), Err(HealthError::NotFinite { .. })));
+    }
+}
@@ -0,0 +1 @@
+pub mod calculator; // Body surface area formulas for clinical dosing
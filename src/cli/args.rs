@@ -0,0 +1,278 @@
+//! Minimal command-line parser: a subcommand followed by `--flag value` pairs.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use fourv_saude::common::sex::Sex;
+use fourv_saude::metabolism::activity::ActivityLevel;
+use fourv_saude::error::HealthError;
+use fourv_saude::i18n::{self, Lang, Message};
+use fourv_saude::parse;
+use fourv_saude::units::{Centimeters, Kilograms, Meters};
+
+use super::output::Format;
+
+/// Errors raised by the command-line interface.
+#[derive(Debug)]
+pub enum CliError {
+    /// The command line itself is malformed (unknown command, missing flag...);
+    /// holds the catalog message and its arguments.
+    Usage(Message, Vec<String>),
+    /// A value was given but is not valid for the calculation.
+    Invalid(HealthError),
+    /// Reading or writing a file failed.
+    Io(std::io::Error),
+}
+
+impl CliError {
+    /// Usage error with the `{}` placeholders of `message` filled by `args`.
+    pub fn usage(message: Message, args: &[&dyn fmt::Display]) -> Self {
+        CliError::Usage(message, args.iter().map(|arg| arg.to_string()).collect())
+    }
+
+    /// Error message in the given language (I/O errors stay in the system's words).
+    pub fn message_in(&self, lang: Lang) -> String {
+        match self {
+            CliError::Usage(message, args) => {
+                let args: Vec<&dyn fmt::Display> = args.iter().map(|arg| arg as &dyn fmt::Display).collect();
+                i18n::format(lang, *message, &args)
+            }
+            CliError::Invalid(err) => i18n::format(lang, Message::InvalidInput, &[&i18n::describe_error(lang, err)]),
+            CliError::Io(err) => err.to_string(),
+        }
+    }
+
+    /// Process exit code: 2 for usage errors, 1 for invalid input.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Usage(..) => 2,
+            CliError::Invalid(_) | CliError::Io(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(..) => write!(f, "{}", self.message_in(Lang::EnUs)),
+            CliError::Invalid(err) => write!(f, "{}", i18n::format(Lang::EnUs, Message::InvalidInput, &[err])),
+            CliError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(err: std::io::Error) -> Self {
+        CliError::Io(err)
+    }
+}
+
+impl From<HealthError> for CliError {
+    fn from(err: HealthError) -> Self {
+        CliError::Invalid(err)
+    }
+}
+
+/// Flags accepted by every subcommand.
+const GLOBAL_FLAGS: &[&str] = &["format", "lang"];
+
+/// Parsed command line.
+#[derive(Debug, Default)]
+pub struct Args {
+    pub command: Option<String>,
+    flags: HashMap<String, String>,
+}
+
+impl Args {
+    /// Parses `<command> [--flag value | --flag=value]...` (program name excluded).
+    pub fn parse<I: IntoIterator<Item = String>>(argv: I) -> Result<Self, CliError> {
+        let mut args = Args::default();
+        let mut iter = argv.into_iter();
+        while let Some(arg) = iter.next() {
+            if arg == "-h" || arg == "--help" {
+                return Ok(Args {
+                    command: Some("help".to_string()),
+                    flags: HashMap::new(),
+                });
+            }
+            if let Some(flag) = arg.strip_prefix("--") {
+                let (name, value) = match flag.split_once('=') {
+                    Some((name, value)) => (name.to_string(), value.to_string()),
+                    None => {
+                        let value = iter
+                            .next()
+                            .ok_or_else(|| CliError::usage(Message::UsageMissingValue, &[&flag]))?;
+                        (flag.to_string(), value)
+                    }
+                };
+                if args.flags.insert(name.clone(), value).is_some() {
+                    return Err(CliError::usage(Message::UsageRepeatedOption, &[&name]));
+                }
+            } else if args.command.is_none() {
+                args.command = Some(arg);
+            } else {
+                return Err(CliError::usage(Message::UsageUnexpectedArgument, &[&arg]));
+            }
+        }
+        Ok(args)
+    }
+
+    /// Fails if any flag outside `allowed` (or the global flags) was given.
+    pub fn allow_only(&self, allowed: &[&str]) -> Result<(), CliError> {
+        let mut unknown: Vec<&String> = self
+            .flags
+            .keys()
+            .filter(|k| !allowed.contains(&k.as_str()) && !GLOBAL_FLAGS.contains(&k.as_str()))
+            .collect();
+        unknown.sort();
+        match unknown.first() {
+            Some(flag) => Err(CliError::usage(Message::UsageUnknownOption, &[flag])),
+            None => Ok(()),
+        }
+    }
+
+    /// Output format selected with `--format`.
+    pub fn format(&self) -> Result<Format, CliError> {
+        Format::parse(self.get("format"))
+    }
+
+    /// Language selected with `--lang`, or taken from the environment.
+    pub fn lang(&self) -> Result<Lang, CliError> {
+        match self.get("lang") {
+            Some(value) => Ok(value.parse::<Lang>()?),
+            None => Ok(Lang::from_env()),
+        }
+    }
+
+    /// Raw value of an optional flag.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.flags.get(name).map(String::as_str)
+    }
+
+    /// Value of a required flag, parsed as a number.
+    pub fn number(&self, name: &'static str) -> Result<f32, CliError> {
+        self.optional_number(name)?.ok_or_else(|| missing(name))
+    }
+
+    /// Value of an optional flag, parsed as a number (comma or dot decimals).
+    pub fn optional_number(&self, name: &'static str) -> Result<Option<f32>, CliError> {
+        Ok(self.get(name).map(|value| parse::parse_number(name, value)).transpose()?)
+    }
+
+    /// Value of the required `--weight` flag; plain numbers are kg, `160lb` pounds.
+    pub fn weight(&self) -> Result<Kilograms, CliError> {
+        self.optional_measurement("weight", parse::parse_weight)?.ok_or_else(|| missing("weight"))
+    }
+
+    /// Value of the required `--height` flag; plain numbers are meters, and
+    /// `175cm`, `69in` and `5'10"` are converted.
+    pub fn height(&self) -> Result<Meters, CliError> {
+        self.optional_height()?.ok_or_else(|| missing("height"))
+    }
+
+    /// Value of the optional `--height` flag, as for [`Args::height`].
+    pub fn optional_height(&self) -> Result<Option<Meters>, CliError> {
+        self.optional_measurement("height", parse::parse_height)
+    }
+
+    /// Value of a required circumference flag; plain numbers are cm, `34in` inches.
+    pub fn circumference(&self, name: &'static str) -> Result<Centimeters, CliError> {
+        self.optional_circumference(name)?.ok_or_else(|| missing(name))
+    }
+
+    /// Value of an optional circumference flag, as for [`Args::circumference`].
+    pub fn optional_circumference(&self, name: &'static str) -> Result<Option<Centimeters>, CliError> {
+        self.optional_measurement(name, |value| parse::parse_circumference(name, value))
+    }
+
+    fn optional_measurement<T>(
+        &self,
+        name: &str,
+        parse: impl Fn(&str) -> Result<T, HealthError>,
+    ) -> Result<Option<T>, CliError> {
+        Ok(self.get(name).map(parse).transpose()?)
+    }
+
+    /// Value of the required `--age` flag, in whole years.
+    pub fn age(&self) -> Result<u32, CliError> {
+        let value = self.get("age").ok_or_else(|| missing("age"))?;
+        parse_value("age", value)
+    }
+
+    /// Value of an optional flag, in whole units (e.g. days).
+    pub fn optional_whole_number(&self, name: &'static str) -> Result<Option<u32>, CliError> {
+        self.get(name).map(|value| parse_value(name, value)).transpose()
+    }
+
+    /// Value of the required `--activity` flag.
+    pub fn activity(&self) -> Result<ActivityLevel, CliError> {
+        let value = self.get("activity").ok_or_else(|| missing("activity"))?;
+        Ok(value.parse::<ActivityLevel>()?)
+    }
+
+    /// Value of the required `--sex` flag.
+    pub fn sex(&self) -> Result<Sex, CliError> {
+        let value = self.get("sex").ok_or_else(|| missing("sex"))?;
+        Ok(value.parse::<Sex>()?)
+    }
+}
+
+fn missing(name: &str) -> CliError {
+    CliError::usage(Message::UsageMissingOption, &[&name])
+}
+
+fn parse_value<T: FromStr>(field: &'static str, value: &str) -> Result<T, CliError> {
+    value.trim().parse::<T>().map_err(|_| {
+        CliError::Invalid(HealthError::Unrecognized {
+            field,
+            input: value.to_string(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Args, CliError> {
+        Args::parse(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_command_and_flags() {
+        let args = parse("bmi --weight 70 --height=1.75").unwrap();
+        assert_eq!(args.command.as_deref(), Some("bmi"));
+        assert_eq!(args.number("weight").unwrap(), 70.0);
+        assert_eq!(args.number("height").unwrap(), 1.75);
+    }
+
+    #[test]
+    fn test_imperial_measurements() {
+        let args = parse("bmi --weight 160lb --height 5'10\" --waist 34in").unwrap();
+        assert!((args.weight().unwrap().0 - 72.57).abs() < 0.01);
+        assert!((args.height().unwrap().0 - 1.778).abs() < 1e-3);
+        assert!((args.circumference("waist").unwrap().0 - 86.36).abs() < 0.01);
+        assert_eq!(args.optional_height().unwrap().map(|height| height.0), Some(args.height().unwrap().0));
+    }
+
+    #[test]
+    fn test_missing_value_is_usage_error() {
+        let err = parse("bmi --weight").unwrap_err();
+        assert_eq!(err.exit_code(), 2);
+    }
+
+    #[test]
+    fn test_invalid_number_is_invalid_input() {
+        let args = parse("bmi --weight abc").unwrap();
+        assert_eq!(args.number("weight").unwrap_err().exit_code(), 1);
+    }
+
+    #[test]
+    fn test_unknown_flag() {
+        let args = parse("bmi --weight 70 --colour red").unwrap();
+        let err = args.allow_only(&["weight", "height"]).unwrap_err();
+        assert_eq!(err.to_string(), "unknown option --colour");
+        assert_eq!(err.message_in(Lang::PtBr), "opção desconhecida --colour");
+    }
+}
@@ -0,0 +1,475 @@
+//! Non-interactive subcommands: each one reads its flags, runs the
+//! calculators and returns the report in the selected format.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+
+use fourv_saude::batch;
+use fourv_saude::bmi::calculator::{BmiCalculator, BmiCategory, BmiData, BmiForAge};
+use fourv_saude::bmi::ideal_weight::{IdealWeightCalculator, WeightTargets};
+use fourv_saude::bmi::index::{BmiIndex, BmiIndexAssessment, BmiIndexCalculator};
+use fourv_saude::bmi::pediatric::GrowthReference;
+use fourv_saude::bmi::standard::BmiReferenceStandard;
+use fourv_saude::bsa::calculator::{BsaCalculator, BsaFormula};
+use fourv_saude::common::date::Date;
+use fourv_saude::common::sex::Sex;
+use fourv_saude::i18n::{self, Lang, Message};
+use fourv_saude::body_fat::anthropometric::{CunBaeCalculator, RfmCalculator, RfmData};
+use fourv_saude::body_fat::comparison::{BodyFatComparator, BodyFatProfile};
+use fourv_saude::body_fat::composition::{BodyCompositionCalculator, BodyCompositionData, LeanMassFormula};
+use fourv_saude::body_fat::navy::{NavyBodyFatCalculator, NavyBodyFatData};
+use fourv_saude::body_fat::skinfold::{DensityEquation, SkinfoldCalculator, SkinfoldData, SkinfoldSite, Skinfolds};
+use fourv_saude::body_fat::{BodyFatAssessment, BodyFatCalculator, BodyFatData, BodyFatMethod};
+use fourv_saude::metabolism::bmr::{BmrFormula, BmrFormulaName};
+use fourv_saude::metabolism::tdee::TdeeCalculator;
+use fourv_saude::metabolism::tmb::{TmbAssessment, TmbCalculator, TmbData};
+use fourv_saude::planning::calculator::{PlanCalculator, PlanSettings};
+use fourv_saude::planning::goal::{DietStyle, Goal};
+use fourv_saude::planning::projection::{ProjectionCalculator, ProjectionSettings};
+use fourv_saude::report::Render;
+use fourv_saude::whr::calculator::{WhrAssessment, WhrCalculator, WhrCalculatorTrait, WhrData};
+use fourv_saude::whr::shape::{BodyShapeCalculator, BodyShapeData};
+use fourv_saude::whr::waist::{
+    IdfPopulation, WaistAssessment, WaistCalculator, WaistData, WaistStandard, WhtrAssessment, WhtrCalculator, WhtrData,
+};
+
+use super::args::{Args, CliError};
+use super::output::{present, Format};
+
+/// BMI assessment followed by the alternative index, if chosen, and the
+/// weight targets for the person's height.
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+struct BmiReport {
+    #[cfg_attr(feature = "json", serde(flatten))]
+    bmi: BmiForAge,
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    index: Option<BmiIndexAssessment>,
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    weight_targets: Option<WeightTargets>,
+}
+
+impl Render for BmiReport {
+    fn render_in(&self, lang: Lang) -> String {
+        let mut sections = vec![self.bmi.render_in(lang)];
+        if let Some(index) = &self.index {
+            sections.push(index.render_in(lang));
+        }
+        if let Some(targets) = &self.weight_targets {
+            sections.push(targets.render_in(lang));
+        }
+        sections.join("\n")
+    }
+}
+
+/// `bmi --weight <kg> --height <m> [--sex <M|F>] [--standard <name>] [--index <name>] [--age <years> [--months <0-11>] [--reference <who|cdc>]]`
+///
+/// Adults also get the chosen alternative index, the healthy weight range and,
+/// with `--sex`, the ideal body weight. With an age under 20 the BMI is
+/// assessed against the BMI-for-age reference instead, `--sex` is required and
+/// `--standard` and `--index` are rejected.
+pub fn bmi(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["weight", "height", "standard", "index", "age", "months", "sex", "reference"])?;
+    let (weight, height) = (args.weight()?, args.height()?);
+    let data = BmiData::new(weight, height)?;
+    let standard = match args.get("standard") {
+        Some(value) => Some(value.parse::<BmiReferenceStandard>()?),
+        None => None,
+    };
+    let adult = || match standard {
+        Some(standard) => BmiCalculator::assess_with_standard(&data, standard),
+        None => BmiCalculator::assess(&data),
+    };
+    let bmi = match args.get("age") {
+        None => {
+            if let Some(flag) = ["months", "reference"].iter().find(|flag| args.get(flag).is_some()) {
+                return Err(CliError::usage(Message::UsageRequiresAge, &[flag]));
+            }
+            BmiForAge::Adult(adult()?)
+        }
+        Some(_) => {
+            let months = args.optional_whole_number("months")?.unwrap_or(0);
+            if months > 11 {
+                return Err(CliError::usage(Message::UsageMonthsRange, &[&months]));
+            }
+            let age_months = args.age()? * 12 + months;
+            let reference = match args.get("reference") {
+                Some(value) => value.parse::<GrowthReference>()?,
+                None => GrowthReference::Who,
+            };
+            match BmiCalculator::assess_for_age_with(&data, age_months, args.sex()?, reference)? {
+                BmiForAge::Adult(_) => BmiForAge::Adult(adult()?),
+                pediatric => pediatric,
+            }
+        }
+    };
+    let index = args.get("index").map(str::parse::<BmiIndex>).transpose()?;
+    let (index, weight_targets) = match bmi {
+        BmiForAge::Adult(_) => {
+            let index = match (index, standard) {
+                (None | Some(BmiIndex::Classic), _) => None,
+                (Some(index), Some(standard)) => Some(BmiIndexCalculator::assess_with_standard(&data, index, standard)?),
+                (Some(index), None) => Some(BmiIndexCalculator::assess(&data, index)?),
+            };
+            let sex = args.get("sex").map(str::parse::<Sex>).transpose()?;
+            (index, Some(IdealWeightCalculator::targets(weight, height, sex, standard.unwrap_or_default())?))
+        }
+        BmiForAge::Pediatric(_) => {
+            if let Some(flag) = ["standard", "index"].iter().find(|flag| args.get(flag).is_some()) {
+                return Err(CliError::usage(Message::UsageAdultsOnly, &[flag]));
+            }
+            (None, None)
+        }
+    };
+    present(&BmiReport { bmi, index, weight_targets }, args.format()?, args.lang()?)
+}
+
+/// `tmb --weight <kg> --height <m> --age <years> --sex <M|F> [--formula <name>] [--lean-mass <kg>]`
+///
+/// Lean-mass equations estimate the lean mass from the PGC when `--lean-mass` is not given.
+pub fn tmb(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["weight", "height", "age", "sex", "formula", "lean-mass"])?;
+    let data = TmbData::new(args.weight()?, args.height()?, args.age()?, args.sex()?)?;
+    let formula = bmr_formula(args, &data)?;
+    present(&TmbCalculator::assess_with(&data, &formula)?, args.format()?, args.lang()?)
+}
+
+/// `tdee --weight <kg> --height <m> --age <years> --sex <M|F> --activity <level> [--formula <name>] [--lean-mass <kg>]`
+pub fn tdee(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["weight", "height", "age", "sex", "activity", "formula", "lean-mass"])?;
+    let data = TmbData::new(args.weight()?, args.height()?, args.age()?, args.sex()?)?;
+    let activity = args.activity()?;
+    let formula = bmr_formula(args, &data)?;
+    present(&TdeeCalculator::assess_with(&data, &formula, activity)?, args.format()?, args.lang()?)
+}
+
+/// `plan <tdee options> --goal <lose|maintain|gain> [--rate <kg/week>] [--diet <style>]`
+///
+/// The rate defaults to 0.5 kg/week and the diet to balanced.
+pub fn plan(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["weight", "height", "age", "sex", "activity", "formula", "lean-mass", "goal", "rate", "diet"])?;
+    let data = TmbData::new(args.weight()?, args.height()?, args.age()?, args.sex()?)?;
+    let activity = args.activity()?;
+    let goal = args
+        .get("goal")
+        .ok_or_else(|| CliError::usage(Message::UsageMissingOption, &[&"goal"]))?
+        .parse::<Goal>()?;
+    let rate = args.optional_number("rate")?.unwrap_or(0.5);
+    let diet = match args.get("diet") {
+        Some(value) => value.parse::<DietStyle>()?,
+        None => DietStyle::Balanced,
+    };
+    let settings = PlanSettings::new(activity, goal, rate, diet)?.with_formula(bmr_formula(args, &data)?);
+    present(&PlanCalculator::plan(&data, &settings)?, args.format()?, args.lang()?)
+}
+
+/// `project <tmb options> --activity <level> --intake <kcal> [--days <n>] [--target <bmi category>] [--start <YYYY-MM-DD>]`
+///
+/// Projects 180 days from today by default.
+pub fn project(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&[
+        "weight", "height", "age", "sex", "activity", "formula", "lean-mass", "intake", "days", "target", "start",
+    ])?;
+    let data = TmbData::new(args.weight()?, args.height()?, args.age()?, args.sex()?)?;
+    let activity = args.activity()?;
+    let days = args.optional_whole_number("days")?.unwrap_or(180);
+    let start = match args.get("start") {
+        Some(value) => value.parse::<Date>()?,
+        None => Date::today(),
+    };
+    let mut settings = ProjectionSettings::new(args.number("intake")?, activity, days, start)?
+        .with_formula(bmr_formula(args, &data)?);
+    if let Some(target) = args.get("target") {
+        settings = settings.with_target(target.parse::<BmiCategory>()?);
+    }
+    present(&ProjectionCalculator::project(&data, &settings)?, args.format()?, args.lang()?)
+}
+
+/// TMB equation from `--formula` (default Harris-Benedict) and `--lean-mass`,
+/// given in kg or as a lean body mass formula (`boer`, `james`, `hume`);
+/// `--lean-mass` is refused for equations that don't use it.
+fn bmr_formula(args: &Args, data: &TmbData) -> Result<BmrFormula, CliError> {
+    let name = match args.get("formula") {
+        Some(value) => value.parse::<BmrFormulaName>()?,
+        None => BmrFormulaName::HarrisBenedict,
+    };
+    let lean_mass = match args.get("lean-mass") {
+        Some(_) if !name.needs_lean_mass() => {
+            return Err(CliError::usage(Message::UsageLeanMassFormula, &[]))
+        }
+        Some(value) => match value.parse::<LeanMassFormula>() {
+            Ok(formula) => formula.try_calculate(data.weight, data.height, data.gender)?,
+            Err(_) => args.number("lean-mass")?,
+        },
+        None if name.needs_lean_mass() => {
+            BodyFatCalculator::try_lean_mass(&BodyFatData::new(data.weight, data.height, data.age, data.gender)?)?
+        }
+        None => 0.0,
+    };
+    Ok(name.with_lean_mass(lean_mass))
+}
+
+/// Skinfold flags of `pgc`, in mm.
+const SKINFOLD_FLAGS: [(&str, SkinfoldSite); 10] = [
+    ("chest", SkinfoldSite::Chest),
+    ("midaxillary", SkinfoldSite::Midaxillary),
+    ("abdomen", SkinfoldSite::Abdomen),
+    ("suprailiac", SkinfoldSite::Suprailiac),
+    ("thigh", SkinfoldSite::Thigh),
+    ("triceps", SkinfoldSite::Triceps),
+    ("biceps", SkinfoldSite::Biceps),
+    ("subscapular", SkinfoldSite::Subscapular),
+    ("lower-back", SkinfoldSite::LowerBack),
+    ("calf", SkinfoldSite::Calf),
+];
+
+/// `pgc --weight <kg> --height <m> --age <years> --sex <M|F>`
+/// or `pgc --method navy --height <m> --neck <cm> --waist <cm> [--hip <cm>] --age <years> --sex <M|F>`
+/// or `pgc --method <jp3|jp7|dw|parrillo> --<site> <mm>... [--density <siri|brozek>] --weight <kg> --age <years> --sex <M|F>`
+/// or `pgc --method <cun-bae|rfm>` with the weight and height, or the height and waist
+/// or `pgc --method compare` with every measurement available
+///
+/// The US Navy method needs the hip circumference for women; the skinfold
+/// methods report which site is missing.
+pub fn pgc(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&pgc_flags())?;
+    if args.get("method") == Some("compare") {
+        return pgc_comparison(args);
+    }
+    present(&body_fat_estimate(args)?, args.format()?, args.lang()?)
+}
+
+/// `composition --weight <kg> --height <m> --sex <M|F>` plus the options of
+/// the chosen `pgc` method (default Deurenberg)
+pub fn composition(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&pgc_flags())?;
+    let data = BodyCompositionData::new(args.weight()?, args.height()?, args.sex()?)?;
+    let estimate = body_fat_estimate(args)?;
+    present(&BodyCompositionCalculator::from_estimate(&data, &estimate)?, args.format()?, args.lang()?)
+}
+
+/// Flags accepted by `pgc` and `composition`.
+fn pgc_flags() -> Vec<&'static str> {
+    let mut flags = vec!["method", "density", "weight", "height", "age", "sex", "neck", "waist", "hip"];
+    flags.extend(SKINFOLD_FLAGS.iter().map(|(flag, _)| *flag));
+    flags
+}
+
+/// PGC by the method chosen with `--method`.
+fn body_fat_estimate(args: &Args) -> Result<BodyFatAssessment, CliError> {
+    let method = match args.get("method") {
+        Some(value) => value.parse::<BodyFatMethod>()?,
+        None => BodyFatMethod::Deurenberg,
+    };
+    Ok(match method {
+        BodyFatMethod::Deurenberg => {
+            let data = BodyFatData::new(args.weight()?, args.height()?, args.age()?, args.sex()?)?;
+            BodyFatCalculator::assess(&data)?
+        }
+        BodyFatMethod::CunBae => {
+            let data = BodyFatData::new(args.weight()?, args.height()?, args.age()?, args.sex()?)?;
+            CunBaeCalculator::assess(&data)?
+        }
+        BodyFatMethod::RelativeFatMass => {
+            let data = RfmData::new(args.height()?, args.circumference("waist")?, args.age()?, args.sex()?)?;
+            RfmCalculator::assess(&data)?
+        }
+        BodyFatMethod::UsNavy => {
+            let hip = args.optional_circumference("hip")?;
+            let data = NavyBodyFatData::new(
+                args.height()?,
+                args.circumference("neck")?,
+                args.circumference("waist")?,
+                hip,
+                args.age()?,
+                args.sex()?,
+            )?;
+            NavyBodyFatCalculator::assess(&data)?
+        }
+        BodyFatMethod::Skinfold(method) => {
+            let equation = match args.get("density") {
+                Some(value) => value.parse::<DensityEquation>()?,
+                None => DensityEquation::Siri,
+            };
+            let data = SkinfoldData::new(skinfolds(args)?, args.weight()?, args.age()?, args.sex()?)?;
+            SkinfoldCalculator::assess(&data, method, equation)?
+        }
+    })
+}
+
+/// `pgc --method compare`: every estimator the given measurements allow.
+fn pgc_comparison(args: &Args) -> Result<String, CliError> {
+    let mut profile = BodyFatProfile::new(args.weight()?, args.height()?, args.age()?, args.sex()?)
+        .with_skinfolds(skinfolds(args)?);
+    if let Some(neck) = args.optional_circumference("neck")? {
+        profile = profile.with_neck(neck);
+    }
+    if let Some(waist) = args.optional_circumference("waist")? {
+        profile = profile.with_waist(waist);
+    }
+    if let Some(hip) = args.optional_circumference("hip")? {
+        profile = profile.with_hip(hip);
+    }
+    present(&BodyFatComparator::compare(&profile)?, args.format()?, args.lang()?)
+}
+
+/// Skinfolds given with the site flags.
+fn skinfolds(args: &Args) -> Result<Skinfolds, CliError> {
+    let mut skinfolds = Skinfolds::default();
+    for (flag, site) in SKINFOLD_FLAGS {
+        if let Some(millimeters) = args.optional_number(flag)? {
+            skinfolds = skinfolds.with(site, millimeters);
+        }
+    }
+    Ok(skinfolds)
+}
+
+/// `whr --waist <cm> --hip <cm> --sex <M|F> [--age <years>]`
+///
+/// With `--age` the risk is graded (low to very high) by the sex and age group chart.
+pub fn whr(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["waist", "hip", "sex", "age"])?;
+    let data = WhrData::new(args.circumference("waist")?, args.circumference("hip")?, args.sex()?)?;
+    match args.get("age") {
+        Some(_) => present(&WhrCalculator::assess_for_age(&data, args.age()?)?, args.format()?, args.lang()?),
+        None => present(&WhrCalculator::assess(&data)?, args.format()?, args.lang()?),
+    }
+}
+
+/// Waist circumference risk followed by the waist-to-height ratio, if the height is known.
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+struct WaistReport {
+    waist: WaistAssessment,
+    #[cfg_attr(feature = "json", serde(skip_serializing_if = "Option::is_none"))]
+    whtr: Option<WhtrAssessment>,
+}
+
+impl Render for WaistReport {
+    fn render_in(&self, lang: Lang) -> String {
+        let mut sections = vec![self.waist.render_in(lang)];
+        if let Some(whtr) = &self.whtr {
+            sections.push(whtr.render_in(lang));
+        }
+        sections.join("\n\n")
+    }
+}
+
+/// `waist --waist <cm> --sex <M|F> [--height <m>] [--standard <who|ncep|idf>] [--population <name>]`
+///
+/// `--population` selects the IDF ethnic cut-offs and implies `--standard idf`.
+pub fn waist(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["waist", "sex", "height", "standard", "population"])?;
+    let waist = args.circumference("waist")?;
+    let population = args.get("population").map(str::parse::<IdfPopulation>).transpose()?;
+    let standard = match (args.get("standard").map(str::parse::<WaistStandard>).transpose()?, population) {
+        (None, None) => WaistStandard::default(),
+        (Some(standard), None) => standard,
+        (None | Some(WaistStandard::Idf(_)), Some(population)) => WaistStandard::Idf(population),
+        (Some(_), Some(_)) => return Err(CliError::usage(Message::UsagePopulationIdf, &[])),
+    };
+    let whtr = match args.optional_height()? {
+        Some(height) => Some(WhtrCalculator::assess(&WhtrData::new(waist, height)?)?),
+        None => None,
+    };
+    let report = WaistReport {
+        waist: WaistCalculator::assess_with_standard(&WaistData::new(waist, args.sex()?)?, standard)?,
+        whtr,
+    };
+    present(&report, args.format()?, args.lang()?)
+}
+
+/// `shape --weight <kg> --height <m> --waist <cm> --age <years> --sex <M|F>`
+pub fn shape(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["weight", "height", "waist", "age", "sex"])?;
+    let data = BodyShapeData::new(
+        args.weight()?,
+        args.height()?,
+        args.circumference("waist")?,
+        args.age()?,
+        args.sex()?,
+    )?;
+    present(&BodyShapeCalculator::calculate(&data)?, args.format()?, args.lang()?)
+}
+
+/// `bsa --weight <kg> --height <m> [--formula <name>]`
+///
+/// Without `--formula` every BSA formula is listed, Mosteller first.
+pub fn bsa(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["weight", "height", "formula"])?;
+    let data = BmiData::new(args.weight()?, args.height()?)?;
+    let result = match args.get("formula") {
+        Some(value) => BsaCalculator::estimate(&data, &[value.parse::<BsaFormula>()?])?,
+        None => BsaCalculator::estimate(&data, &BsaFormula::ALL)?,
+    };
+    present(&result, args.format()?, args.lang()?)
+}
+
+/// Every assessment for one person, as printed by `all`.
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+struct AllReport {
+    bmi: BmiForAge,
+    tmb: TmbAssessment,
+    pgc: BodyFatAssessment,
+    whr: Option<WhrAssessment>,
+}
+
+impl Render for AllReport {
+    fn render_in(&self, lang: Lang) -> String {
+        let mut sections = vec![self.bmi.render_in(lang), self.tmb.render_in(lang), self.pgc.render_in(lang)];
+        if let Some(whr) = &self.whr {
+            sections.push(whr.render_in(lang));
+        }
+        sections.join("\n\n")
+    }
+}
+
+/// `all --weight <kg> --height <m> --age <years> --sex <M|F> [--waist <cm> --hip <cm>]`
+///
+/// WHR is included only when both circumferences are given.
+pub fn all(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["weight", "height", "age", "sex", "waist", "hip"])?;
+    let (weight, height, age, sex) = (args.weight()?, args.height()?, args.age()?, args.sex()?);
+
+    let whr = match (args.optional_circumference("waist")?, args.optional_circumference("hip")?) {
+        (Some(waist), Some(hip)) => Some(WhrCalculator::assess(&WhrData::new(waist, hip, sex)?)?),
+        (None, None) => None,
+        _ => return Err(CliError::usage(Message::UsageWaistAndHip, &[])),
+    };
+    let report = AllReport {
+        bmi: BmiCalculator::assess_for_age(&BmiData::new(weight, height)?, age, sex)?,
+        tmb: TmbCalculator::assess(&TmbData::new(weight, height, age, sex)?)?,
+        pgc: BodyFatCalculator::assess(&BodyFatData::new(weight, height, age, sex)?)?,
+        whr,
+    };
+    present(&report, args.format()?, args.lang()?)
+}
+
+/// `batch --input <file.csv|-> [--output <file.csv>]`
+///
+/// Streams the input CSV through every calculator; results go to `--output`
+/// or stdout, and a summary is returned for printing. The output is always
+/// CSV, so `--format json` is a usage error.
+pub fn batch(args: &Args) -> Result<String, CliError> {
+    args.allow_only(&["input", "output"])?;
+    if args.format()? == Format::Json {
+        return Err(CliError::usage(Message::UsageBatchJson, &[]));
+    }
+    let input = args
+        .get("input")
+        .ok_or_else(|| CliError::usage(Message::UsageMissingOption, &[&"input"]))?;
+
+    let reader: Box<dyn io::BufRead> = if input == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(BufReader::new(File::open(input)?))
+    };
+    let lang = args.lang()?;
+    let summary = match args.get("output") {
+        Some(path) => batch::process_csv(reader, BufWriter::new(File::create(path)?), lang)?,
+        None => batch::process_csv(reader, io::stdout().lock(), lang)?,
+    };
+    eprintln!(
+        "{}",
+        i18n::format(lang, Message::BatchSummary, &[&summary.rows, &summary.rows_with_errors])
+    );
+    Ok(String::new())
+}
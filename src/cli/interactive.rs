@@ -0,0 +1,188 @@
+//! Interactive menu: the original question-and-answer loop over stdin.
+
+use std::io; // Importing the standard input/output library
+use fourv_saude::bmi::calculator::{BmiCalculator, BmiData}; // Importing enum BMI calculator and data structures
+use fourv_saude::body_fat;
+use fourv_saude::error::HealthError;
+use fourv_saude::i18n::{self, text, Lang, Message};
+use fourv_saude::parse;
+use fourv_saude::report::Render;
+use fourv_saude::common::sex::Sex;
+use fourv_saude::metabolism::activity::ActivityLevel;
+use fourv_saude::metabolism::tdee::TdeeCalculator;
+use fourv_saude::metabolism::tmb::{TmbCalculator, TmbData};
+use fourv_saude::whr::calculator::{WhrCalculator, WhrCalculatorTrait, WhrData};
+
+/// Runs the menu loop, in the given language, until the user chooses to exit.
+/// Fails when stdin is closed or unreadable, instead of prompting forever.
+pub fn run(lang: Lang) -> io::Result<()> {
+    loop { // Start of the main loop until the user decides to exit
+        println!("\n{}", text(lang, Message::MenuTitle));
+        println!("{}", text(lang, Message::MenuBmi));
+        println!("{}", text(lang, Message::MenuTmb));
+        println!("{}", text(lang, Message::MenuPgc));
+        println!("{}", text(lang, Message::MenuWhr));
+        println!("{}", text(lang, Message::MenuTdee));
+        println!("{}", text(lang, Message::MenuExit));
+
+        let choice = read_input_as_u32(lang)?; // Read user input as u32
+
+        match choice { // Match user input against available options
+            1 => { // BMI Calculation
+                println!("{}", text(lang, Message::PromptWeight));
+                let weight = read_measurement(lang, parse::parse_weight)?; // Accepts 70,5 / 70.5 / 160lb
+
+                println!("{}", text(lang, Message::PromptHeight));
+                let height = read_measurement(lang, parse::parse_height)?;
+
+                let result = BmiData::new(weight, height).and_then(|data| BmiCalculator::assess(&data));
+                print_assessment(lang, result);
+            }
+            2 => { // TMB Calculation
+                println!("{}", text(lang, Message::PromptWeight));
+                let weight = read_measurement(lang, parse::parse_weight)?;
+
+                println!("{}", text(lang, Message::PromptHeight));
+                let height = read_measurement(lang, parse::parse_height)?;
+
+                println!("{}", text(lang, Message::PromptAge));
+                let age = read_measurement(lang, parse::parse_age)?;
+
+                let gender = match read_sex(lang)? {
+                    Some(sex) => sex,
+                    None => continue,
+                };
+
+                let result = TmbData::new(weight, height, age, gender).and_then(|data| TmbCalculator::assess(&data));
+                print_assessment(lang, result);
+            }
+            3 => { // Body Fat Percentage (PGC) Calculation
+                println!("{}", text(lang, Message::PromptWeight));
+                let weight = read_measurement(lang, parse::parse_weight)?;
+
+                println!("{}", text(lang, Message::PromptHeight));
+                let height = read_measurement(lang, parse::parse_height)?;
+
+                println!("{}", text(lang, Message::PromptAge));
+                let age = read_measurement(lang, parse::parse_age)?;
+
+                let gender = match read_sex(lang)? {
+                    Some(sex) => sex,
+                    None => continue,
+                };
+
+                let result = body_fat::BodyFatData::new(weight, height, age, gender)
+                    .and_then(|data| body_fat::BodyFatCalculator::assess(&data));
+                print_assessment(lang, result);
+            }
+            4 => { // Waist-to-Hip Ratio (WHR) Calculation
+                let gender = match read_sex(lang)? {
+                    Some(sex) => sex,
+                    None => continue,
+                };
+
+                println!("{}", text(lang, Message::PromptWaist));
+                let waist = read_measurement(lang, |input| parse::parse_circumference("waist", input))?; // cintura
+
+                println!("{}", text(lang, Message::PromptHip));
+                let hip = read_measurement(lang, |input| parse::parse_circumference("hip", input))?; // quadril
+
+                let result = WhrData::new(waist, hip, gender).and_then(|data| WhrCalculator::assess(&data));
+                print_assessment(lang, result);
+            }
+            5 => { // Total Daily Energy Expenditure (TDEE) Calculation
+                println!("{}", text(lang, Message::PromptWeight));
+                let weight = read_measurement(lang, parse::parse_weight)?;
+
+                println!("{}", text(lang, Message::PromptHeight));
+                let height = read_measurement(lang, parse::parse_height)?;
+
+                println!("{}", text(lang, Message::PromptAge));
+                let age = read_measurement(lang, parse::parse_age)?;
+
+                let gender = match read_sex(lang)? {
+                    Some(sex) => sex,
+                    None => continue,
+                };
+
+                println!("{}", text(lang, Message::PromptActivity));
+                let activity = read_activity_level(lang)?;
+
+                let result = TmbData::new(weight, height, age, gender)
+                    .and_then(|data| TdeeCalculator::assess(&data, activity));
+                print_assessment(lang, result);
+            }
+            0 => {
+                println!("{}", text(lang, Message::Exiting));
+                return Ok(());
+            }
+            _ => {
+                println!("{}", text(lang, Message::InvalidOption));
+            }
+        }
+    }
+}
+
+/// Prints a rendered assessment, or the validation error that prevented it
+fn print_assessment<T: Render>(lang: Lang, result: Result<T, HealthError>) {
+    match result {
+        Ok(assessment) => println!("{}", assessment.render_in(lang)),
+        Err(err) => println!("{}", i18n::format(lang, Message::InvalidInput, &[&i18n::describe_error(lang, &err)])),
+    }
+}
+
+/// Reads one line from stdin; end of input is an `UnexpectedEof` error
+fn read_line() -> io::Result<String> {
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input)? == 0 {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "end of input"));
+    }
+    Ok(input)
+}
+
+/// Reads a measurement with the locale-aware parser, asking again until it is valid
+fn read_measurement<T>(lang: Lang, parse: impl Fn(&str) -> Result<T, HealthError>) -> io::Result<T> {
+    loop {
+        if let Ok(value) = parse(&read_line()?) {
+            return Ok(value);
+        }
+        println!("{}", text(lang, Message::InvalidNumber));
+    }
+}
+
+/// Reads an activity level (1-5 or its name), asking again until it is valid
+fn read_activity_level(lang: Lang) -> io::Result<ActivityLevel> {
+    loop {
+        if let Ok(level) = read_input_as_string()?.parse::<ActivityLevel>() {
+            return Ok(level);
+        }
+        println!("{}", text(lang, Message::InvalidActivity));
+    }
+}
+
+/// Reads and returns user input as u32
+fn read_input_as_u32(lang: Lang) -> io::Result<u32> {
+    loop {
+        if let Ok(value) = read_line()?.trim().parse::<u32>() {
+            return Ok(value);
+        }
+        println!("{}", text(lang, Message::InvalidWholeNumber));
+    }
+}
+
+/// Reads and returns user input as trimmed String
+fn read_input_as_string() -> io::Result<String> {
+    Ok(read_line()?.trim().to_string())
+}
+
+/// Asks for the user's sex, returning None (after a message) on invalid input
+fn read_sex(lang: Lang) -> io::Result<Option<Sex>> {
+    println!("{}", text(lang, Message::PromptSex));
+    match read_input_as_string()?.parse::<Sex>() {
+        Ok(sex) => Ok(Some(sex)),
+        Err(_) => {
+            println!("{}", text(lang, Message::InvalidSex));
+            Ok(None)
+        }
+    }
+}
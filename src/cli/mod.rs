@@ -0,0 +1,115 @@
+//! Command-line interface: subcommands for scripts plus the interactive menu.
+
+mod args;
+mod commands;
+mod interactive;
+mod output;
+
+use args::{Args, CliError};
+use fourv_saude::i18n::{Lang, Message};
+
+const USAGE: &str = "\
+Usage: fourv_saude [COMMAND] [OPTIONS]
+
+Commands:
+  interactive  Question-and-answer menu (default when no command is given)
+  bmi          --weight <kg> --height <m> [--sex <M|F>] [--standard <who|asian-pacific|who-expert|jasso>]
+               [--index <classic|prime|trefethen|ponderal>]
+               [--age <years> [--months <0-11>] [--reference <who|cdc>]]
+               (healthy weight range and ideal body weight for adults; with --sex, IBW formulas;
+               BMI-for-age z-score and percentile under 20, which requires --sex)
+  tmb          --weight <kg> --height <m> --age <years> --sex <M|F>
+               [--formula <harris-benedict|mifflin-st-jeor|katch-mcardle|cunningham>]
+               [--lean-mass <kg|boer|james|hume>] (default for lean-mass formulas: estimated from PGC)
+  tdee         the tmb options plus --activity <sedentary|light|moderate|very|extra>
+  plan         the tdee options plus --goal <lose|maintain|gain> [--rate <kg/week>]
+               [--diet <balanced|low-carb|high-protein|keto>]
+  project      the tdee options plus --intake <kcal/day> [--days <n>] [--start <YYYY-MM-DD>]
+               [--target <underweight|normal|overweight|obesity-1|obesity-2|obesity-3>]
+  pgc          --weight <kg> --height <m> --age <years> --sex <M|F>
+               or --method navy --height <m> --neck <cm> --waist <cm> [--hip <cm>] --age <years> --sex <M|F>
+               (US Navy circumference method; --hip is required for women)
+               or --method <jp3|jp7|dw|parrillo> --weight <kg> --age <years> --sex <M|F> [--density <siri|brozek>]
+               with the skinfolds in mm: --chest --midaxillary --abdomen --suprailiac --thigh
+               --triceps --biceps --subscapular --lower-back --calf
+               or --method cun-bae --weight <kg> --height <m> --age <years> --sex <M|F>
+               or --method rfm --height <m> --waist <cm> --age <years> --sex <M|F>
+               or --method compare with the weight, height, age, sex and any of the above
+               (runs every estimator the measurements allow and shows the spread)
+  composition  --weight <kg> --height <m> --sex <M|F> plus the options of a pgc method
+               (fat mass, fat-free mass, lean body mass formulas and FFMI)
+  whr          --waist <cm> --hip <cm> --sex <M|F> [--age <years>]
+               (with --age, risk graded from low to very high by age group)
+  waist        --waist <cm> --sex <M|F> [--height <m>] [--standard <who|ncep|idf>]
+               [--population <europid|south-asian|chinese|japanese>] (IDF ethnic cut-offs)
+               (waist circumference risk; with --height, the waist-to-height ratio)
+  shape        --weight <kg> --height <m> --waist <cm> --age <years> --sex <M|F>
+               (ABSI with its age and sex z-score, Body Roundness Index and Conicity Index; adults)
+  bsa          --weight <kg> --height <m> [--formula <mosteller|dubois|haycock|gehan-george|boyd>]
+               (body surface area in m²; every formula when --formula is not given)
+  all          --weight <kg> --height <m> --age <years> --sex <M|F> [--waist <cm> --hip <cm>]
+  batch        --input <file.csv|-> [--output <file.csv>]
+               CSV columns: weight,height,age,sex[,waist,hip] (output is always CSV)
+  help         Show this message
+
+Measurements are metric by default; --weight also takes pounds (160lb), --height
+centimeters, inches or feet and inches (175cm, 69in, 5'10\"), and circumferences
+inches (34in). Comma or dot decimals are both accepted.
+
+Options:
+  --format <text|json>  Output format of the non-interactive commands (default: text)
+  --lang <pt-BR|en-US>  Language of reports and prompts (default: from LC_ALL/LC_MESSAGES/LANG)
+
+Exit codes: 0 success, 1 invalid input, 2 usage error";
+
+/// Runs the command line (program name excluded) and returns the process exit code.
+pub fn run(argv: Vec<String>) -> i32 {
+    let args = Args::parse(argv);
+    // Errors are reported in the requested language when it can be determined
+    let lang = args
+        .as_ref()
+        .ok()
+        .and_then(|args| args.lang().ok())
+        .unwrap_or_else(Lang::from_env);
+    let result = args.and_then(|args| dispatch(&args));
+    match result {
+        Ok(output) => {
+            if !output.is_empty() {
+                println!("{}", output);
+            }
+            0
+        }
+        Err(err) => {
+            eprintln!("error: {}", err.message_in(lang));
+            if let CliError::Usage(..) = err {
+                eprintln!("\n{}", USAGE);
+            }
+            err.exit_code()
+        }
+    }
+}
+
+fn dispatch(args: &Args) -> Result<String, CliError> {
+    match args.command.as_deref() {
+        None | Some("interactive") => {
+            args.allow_only(&[])?;
+            interactive::run(args.lang()?)?;
+            Ok(String::new())
+        }
+        Some("bmi") => commands::bmi(args),
+        Some("tmb") => commands::tmb(args),
+        Some("tdee") => commands::tdee(args),
+        Some("plan") => commands::plan(args),
+        Some("project") => commands::project(args),
+        Some("pgc") => commands::pgc(args),
+        Some("composition") => commands::composition(args),
+        Some("whr") => commands::whr(args),
+        Some("waist") => commands::waist(args),
+        Some("shape") => commands::shape(args),
+        Some("bsa") => commands::bsa(args),
+        Some("all") => commands::all(args),
+        Some("batch") => commands::batch(args),
+        Some("help") => Ok(USAGE.to_string()),
+        Some(other) => Err(CliError::usage(Message::UsageUnknownCommand, &[&other])),
+    }
+}
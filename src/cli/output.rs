@@ -0,0 +1,50 @@
+//! Output formats for the subcommands: rendered text or JSON.
+
+use fourv_saude::i18n::{Lang, Message};
+use fourv_saude::report::Render;
+
+use super::args::CliError;
+
+/// How a subcommand prints its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    /// Parses the `--format` option (`text` or `json`).
+    pub fn parse(value: Option<&str>) -> Result<Self, CliError> {
+        match value.map(|v| v.to_lowercase()).as_deref() {
+            None | Some("text") => Ok(Format::Text),
+            Some("json") if cfg!(feature = "json") => Ok(Format::Json),
+            Some("json") => Err(CliError::usage(Message::UsageJsonFeature, &[])),
+            Some(other) => Err(CliError::usage(Message::UsageUnknownFormat, &[&other])),
+        }
+    }
+}
+
+/// A result that can be printed in every supported format.
+#[cfg(feature = "json")]
+pub trait Report: Render + serde::Serialize {}
+#[cfg(feature = "json")]
+impl<T: Render + serde::Serialize> Report for T {}
+
+/// A result that can be printed in every supported format.
+#[cfg(not(feature = "json"))]
+pub trait Report: Render {}
+#[cfg(not(feature = "json"))]
+impl<T: Render> Report for T {}
+
+/// Formats a result for printing; `lang` applies to the text format only.
+pub fn present<T: Report>(value: &T, format: Format, lang: Lang) -> Result<String, CliError> {
+    match format {
+        Format::Text => Ok(value.render_in(lang)),
+        #[cfg(feature = "json")]
+        // Not caused by the command line, so reported like an I/O failure (exit code 1)
+        Format::Json => serde_json::to_string_pretty(value)
+            .map_err(|err| CliError::Io(std::io::Error::other(format!("could not serialize result: {}", err)))),
+        #[cfg(not(feature = "json"))]
+        Format::Json => unreachable!("Format::parse rejects json without the feature"),
+    }
+}
@@ -0,0 +1,166 @@
+//! Minimal proleptic Gregorian calendar date, enough to derive an age from a
+//! birth date and to place projected results on the calendar.
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::HealthError;
+
+/// A calendar date (year, month 1-12, day 1-31).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a date, rejecting months and days that do not exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, HealthError> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(HealthError::Unrecognized {
+                field: "date",
+                input: format!("{:04}-{:02}-{:02}", year, month, day),
+            });
+        }
+        Ok(Date { year, month, day })
+    }
+
+    /// Today's date in UTC, according to the system clock.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Date::from_days_since_epoch((seconds / 86_400) as i64)
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Number of days since 1970-01-01 (negative before it).
+    pub fn days_since_epoch(&self) -> i64 {
+        // Howard Hinnant's days_from_civil algorithm
+        let y = if self.month <= 2 { self.year - 1 } else { self.year } as i64;
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let m = self.month as i64;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Inverse of [`Date::days_since_epoch`].
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+        Date { year, month, day }
+    }
+
+    /// Returns the date `days` days later (or earlier, if negative).
+    pub fn add_days(&self, days: i64) -> Self {
+        Date::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Completed years between `self` (e.g. a birth date) and `on`.
+    pub fn years_until(&self, on: &Date) -> u32 {
+        if on <= self {
+            return 0;
+        }
+        let mut years = on.year - self.year;
+        if (on.month, on.day) < (self.month, self.day) {
+            years -= 1;
+        }
+        years as u32
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl FromStr for Date {
+    type Err = HealthError;
+
+    /// Parses an ISO 8601 date (`YYYY-MM-DD`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid = || HealthError::Unrecognized {
+            field: "date",
+            input: input.trim().to_string(),
+        };
+        let parts: Vec<&str> = input.trim().split('-').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+        let year = parts[0].parse::<i32>().map_err(|_| invalid())?;
+        let month = parts[1].parse::<u32>().map_err(|_| invalid())?;
+        let day = parts[2].parse::<u32>().map_err(|_| invalid())?;
+        Date::new(year, month, day)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_round_trip() {
+        let date = Date::new(2024, 2, 29).unwrap();
+        assert_eq!(date.days_since_epoch(), 19_782);
+        assert_eq!(Date::from_days_since_epoch(19_782), date);
+        assert_eq!(Date::from_days_since_epoch(0), Date::new(1970, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn test_add_days_across_year() {
+        let date = Date::new(2023, 12, 30).unwrap();
+        assert_eq!(date.add_days(3), Date::new(2024, 1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_years_until_birthday() {
+        let birth = Date::new(1990, 6, 15).unwrap();
+        assert_eq!(birth.years_until(&Date::new(2024, 6, 14).unwrap()), 33);
+        assert_eq!(birth.years_until(&Date::new(2024, 6, 15).unwrap()), 34);
+    }
+
+    #[test]
+    fn test_rejects_invalid_date() {
+        assert!(Date::new(2023, 2, 29).is_err());
+        assert!("2024-13-01".parse::<Date>().is_err());
+        assert_eq!("2024-03-05".parse::<Date>().unwrap().to_string(), "2024-03-05");
+    }
+}
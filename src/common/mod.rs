@@ -0,0 +1,3 @@
+pub mod date; // Calendar dates used for birth dates and projections
+pub mod profile; // This is needed to expose the PersonProfile model
+pub mod sex; // This is needed to expose the shared Sex type
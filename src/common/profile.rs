@@ -0,0 +1,188 @@
+//! A single record describing a person, from which the input of every
+//! calculator (BMI, TMB, PGC and WHR) can be derived.
+
+use crate::bmi::calculator::BmiData;
+use crate::body_fat::BodyFatData;
+use crate::common::date::Date;
+use crate::common::sex::Sex;
+use crate::error::{self, HealthError};
+use crate::metabolism::activity::ActivityLevel;
+use crate::metabolism::tmb::TmbData;
+use crate::units::{Centimeters, Kilograms, Meters};
+use crate::whr::calculator::WhrData;
+
+/// Anthropometric profile of a person.
+///
+/// Weight is in kilograms, height in meters and circumferences in centimeters.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PersonProfile {
+    pub weight: f32,
+    pub height: f32,
+    pub age: u32,
+    pub birth_date: Option<Date>,
+    pub sex: Sex,
+    pub waist: Option<f32>,
+    pub hip: Option<f32>,
+    pub neck: Option<f32>,
+    pub activity_level: Option<ActivityLevel>,
+}
+
+impl PersonProfile {
+    /// Creates a validated profile with the measurements every calculator needs.
+    pub fn new(
+        weight: impl Into<Kilograms>,
+        height: impl Into<Meters>,
+        age: u32,
+        sex: Sex,
+    ) -> Result<Self, HealthError> {
+        let weight = weight.into().0;
+        let height = height.into().0;
+        error::validate_measurement("weight", weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_measurement("height", height, error::HEIGHT_RANGE_M)?;
+        error::validate_age(age)?;
+        Ok(PersonProfile {
+            weight,
+            height,
+            age,
+            birth_date: None,
+            sex,
+            waist: None,
+            hip: None,
+            neck: None,
+            activity_level: None,
+        })
+    }
+
+    /// Creates a profile whose age is derived from a birth date, as of `today`.
+    /// A birth date after `today` is an error.
+    pub fn with_birth_date(
+        weight: impl Into<Kilograms>,
+        height: impl Into<Meters>,
+        birth_date: Date,
+        today: &Date,
+        sex: Sex,
+    ) -> Result<Self, HealthError> {
+        if birth_date > *today {
+            return Err(HealthError::Implausible {
+                field: "birth date",
+                value: birth_date.year() as f32,
+                min: (today.year() - error::MAX_AGE_YEARS as i32) as f32,
+                max: today.year() as f32,
+            });
+        }
+        let mut profile = PersonProfile::new(weight, height, birth_date.years_until(today), sex)?;
+        profile.birth_date = Some(birth_date);
+        Ok(profile)
+    }
+
+    /// Sets the waist circumference (cm).
+    pub fn with_waist(mut self, waist: impl Into<Centimeters>) -> Result<Self, HealthError> {
+        self.waist = Some(error::validate_measurement("waist", waist.into().0, error::CIRCUMFERENCE_RANGE_CM)?);
+        Ok(self)
+    }
+
+    /// Sets the hip circumference (cm).
+    pub fn with_hip(mut self, hip: impl Into<Centimeters>) -> Result<Self, HealthError> {
+        self.hip = Some(error::validate_measurement("hip", hip.into().0, error::CIRCUMFERENCE_RANGE_CM)?);
+        Ok(self)
+    }
+
+    /// Sets the neck circumference (cm).
+    pub fn with_neck(mut self, neck: impl Into<Centimeters>) -> Result<Self, HealthError> {
+        self.neck = Some(error::validate_measurement("neck", neck.into().0, error::CIRCUMFERENCE_RANGE_CM)?);
+        Ok(self)
+    }
+
+    /// Sets the habitual activity level.
+    pub fn with_activity_level(mut self, level: ActivityLevel) -> Self {
+        self.activity_level = Some(level);
+        self
+    }
+
+    /// Input for the BMI calculator.
+    pub fn bmi_data(&self) -> BmiData {
+        BmiData { weight: self.weight, height: self.height }
+    }
+
+    /// Input for the TMB calculator.
+    pub fn tmb_data(&self) -> TmbData {
+        TmbData {
+            weight: self.weight,
+            height: self.height,
+            age: self.age,
+            gender: self.sex,
+        }
+    }
+
+    /// Input for the body fat calculator.
+    pub fn body_fat_data(&self) -> BodyFatData {
+        BodyFatData {
+            weight: self.weight,
+            height: self.height,
+            age: self.age,
+            gender: self.sex,
+        }
+    }
+
+    /// Input for the WHR calculator; fails if waist or hip were not measured.
+    pub fn whr_data(&self) -> Result<WhrData, HealthError> {
+        Ok(WhrData {
+            waist_circumference: self.waist.ok_or(HealthError::Missing { field: "waist" })?,
+            hip_circumference: self.hip.ok_or(HealthError::Missing { field: "hip" })?,
+            gender: self.sex,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bmi::calculator::BmiCalculator;
+    use crate::whr::calculator::{WhrCalculator, WhrCalculatorTrait};
+
+    #[test]
+    fn test_profile_drives_every_calculator() {
+        let profile = PersonProfile::new(70.0, 1.75, 30, Sex::Male)
+            .and_then(|p| p.with_waist(90.0))
+            .and_then(|p| p.with_hip(100.0))
+            .unwrap();
+
+        let bmi = BmiCalculator::calculate(&profile.bmi_data());
+        assert!((bmi - 22.86).abs() < 0.01);
+        assert_eq!(profile.tmb_data().gender, Sex::Male);
+        assert_eq!(profile.body_fat_data().age, 30);
+        let whr = WhrCalculator::calculate(&profile.whr_data().unwrap());
+        assert!((whr - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_whr_data_requires_circumferences() {
+        let profile = PersonProfile::new(70.0, 1.75, 30, Sex::Female).unwrap();
+        assert_eq!(profile.whr_data().err(), Some(HealthError::Missing { field: "waist" }));
+    }
+
+    #[test]
+    fn test_age_from_birth_date() {
+        let birth = Date::new(1990, 6, 15).unwrap();
+        let today = Date::new(2024, 1, 1).unwrap();
+        let profile = PersonProfile::with_birth_date(60.0, 1.65, birth, &today, Sex::Female).unwrap();
+        assert_eq!(profile.age, 33);
+        assert_eq!(profile.birth_date, Some(birth));
+    }
+
+    #[test]
+    fn test_rejects_birth_date_after_today() {
+        let birth = Date::new(2024, 3, 1).unwrap();
+        let today = Date::new(2024, 1, 1).unwrap();
+        let result = PersonProfile::with_birth_date(3.5, 0.5, birth, &today, Sex::Female);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "birth date", .. })));
+        assert!(PersonProfile::with_birth_date(3.5, 0.5, today, &today, Sex::Female).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_invalid_circumference() {
+        let profile = PersonProfile::new(70.0, 1.75, 30, Sex::Male).unwrap();
+        assert_eq!(profile.with_neck(-1.0).err(), Some(HealthError::Negative { field: "neck", value: -1.0 }));
+    }
+}
@@ -0,0 +1,66 @@
+//! Biological sex shared by every calculator whose reference values differ
+//! between men and women (TMB, PGC, WHR).
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::HealthError;
+
+/// Biological sex used to select sex-specific formulas and cut-offs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sex {
+    Male,
+    Female,
+}
+
+impl FromStr for Sex {
+    type Err = HealthError;
+
+    /// Parses `M`/`F` as well as the full English and Portuguese words.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "m" | "male" | "man" | "h" | "homem" | "masculino" => Ok(Sex::Male),
+            "f" | "female" | "woman" | "mulher" | "feminino" => Ok(Sex::Female),
+            _ => Err(HealthError::Unrecognized {
+                field: "sex",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Sex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sex::Male => write!(f, "male"),
+            Sex::Female => write!(f, "female"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_short_codes() {
+        assert_eq!("M".parse::<Sex>(), Ok(Sex::Male));
+        assert_eq!(" f ".parse::<Sex>(), Ok(Sex::Female));
+    }
+
+    #[test]
+    fn test_parse_portuguese_words() {
+        assert_eq!("Masculino".parse::<Sex>(), Ok(Sex::Male));
+        assert_eq!("feminino".parse::<Sex>(), Ok(Sex::Female));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let result = "x".parse::<Sex>();
+        assert_eq!(
+            result,
+            Err(HealthError::Unrecognized { field: "sex", input: "x".to_string() })
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Crate-wide error type shared by every health calculator.
+//!
+//! Calculators never return NaN or infinity through their fallible paths:
+//! inputs are validated first and a `HealthError` describes what was wrong.
+
+use std::fmt;
+
+use crate::i18n::{self, Lang};
+
+/// Errors produced while validating inputs or computing a health index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthError {
+    /// The value is NaN or infinite.
+    NotFinite { field: &'static str },
+    /// The value is below zero.
+    Negative { field: &'static str, value: f32 },
+    /// The value is exactly zero where a positive measurement is required.
+    Zero { field: &'static str },
+    /// The value is finite and positive but outside the physiological range.
+    Implausible {
+        field: &'static str,
+        value: f32,
+        min: f32,
+        max: f32,
+    },
+    /// The calculation itself produced a non-finite result.
+    InvalidResult { calculation: &'static str },
+    /// A measurement required by the calculation was not provided.
+    Missing { field: &'static str },
+    /// Text input could not be interpreted for the given field.
+    Unrecognized { field: &'static str, input: String },
+    /// A calorie target (kcal/day) falls below the safe minimum for the person.
+    UnsafeTarget { target: f32, minimum: f32 },
+}
+
+impl fmt::Display for HealthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", i18n::describe_error(Lang::EnUs, self))
+    }
+}
+
+impl std::error::Error for HealthError {}
+
+/// Plausible range for body weight, in kilograms.
+pub const WEIGHT_RANGE_KG: (f32, f32) = (1.0, 650.0);
+/// Plausible range for standing height, in meters.
+pub const HEIGHT_RANGE_M: (f32, f32) = (0.3, 2.75);
+/// Plausible range for body circumferences (waist, hip, neck), in centimeters.
+pub const CIRCUMFERENCE_RANGE_CM: (f32, f32) = (10.0, 300.0);
+/// Plausible range for a single skinfold caliper reading, in millimeters.
+pub const SKINFOLD_RANGE_MM: (f32, f32) = (1.0, 100.0);
+/// Maximum plausible age, in years.
+pub const MAX_AGE_YEARS: u32 = 120;
+
+/// Validates a measurement: finite, positive and inside `range` (inclusive).
+pub fn validate_measurement(
+    field: &'static str,
+    value: f32,
+    range: (f32, f32),
+) -> Result<f32, HealthError> {
+    if !value.is_finite() {
+        return Err(HealthError::NotFinite { field });
+    }
+    if value < 0.0 {
+        return Err(HealthError::Negative { field, value });
+    }
+    if value == 0.0 {
+        return Err(HealthError::Zero { field });
+    }
+    let (min, max) = range;
+    if value < min || value > max {
+        return Err(HealthError::Implausible { field, value, min, max });
+    }
+    Ok(value)
+}
+
+/// Validates an age in whole years.
+pub fn validate_age(age: u32) -> Result<u32, HealthError> {
+    if age > MAX_AGE_YEARS {
+        return Err(HealthError::Implausible {
+            field: "age",
+            value: age as f32,
+            min: 0.0,
+            max: MAX_AGE_YEARS as f32,
+        });
+    }
+    Ok(age)
+}
+
+/// Turns a computed value into an error if it is NaN or infinite.
+pub fn ensure_finite(calculation: &'static str, value: f32) -> Result<f32, HealthError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(HealthError::InvalidResult { calculation })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_measurement_accepts_plausible_value() {
+        assert_eq!(validate_measurement("weight", 70.0, WEIGHT_RANGE_KG), Ok(70.0));
+    }
+
+    #[test]
+    fn test_validate_measurement_rejects_nan_and_infinity() {
+        assert_eq!(
+            validate_measurement("weight", f32::NAN, WEIGHT_RANGE_KG),
+            Err(HealthError::NotFinite { field: "weight" })
+        );
+        assert_eq!(
+            validate_measurement("height", f32::INFINITY, HEIGHT_RANGE_M),
+            Err(HealthError::NotFinite { field: "height" })
+        );
+    }
+
+    #[test]
+    fn test_validate_measurement_rejects_negative_and_zero() {
+        assert_eq!(
+            validate_measurement("height", -1.75, HEIGHT_RANGE_M),
+            Err(HealthError::Negative { field: "height", value: -1.75 })
+        );
+        assert_eq!(
+            validate_measurement("height", 0.0, HEIGHT_RANGE_M),
+            Err(HealthError::Zero { field: "height" })
+        );
+    }
+
+    #[test]
+    fn test_validate_measurement_rejects_implausible() {
+        // Height typed in centimeters instead of meters
+        let result = validate_measurement("height", 175.0, HEIGHT_RANGE_M);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "height", .. })));
+    }
+
+    #[test]
+    fn test_validate_age() {
+        assert_eq!(validate_age(30), Ok(30));
+        assert!(validate_age(150).is_err());
+    }
+
+    #[test]
+    fn test_ensure_finite() {
+        assert_eq!(ensure_finite("bmi", 22.0), Ok(22.0));
+        assert_eq!(
+            ensure_finite("bmi", f32::NAN),
+            Err(HealthError::InvalidResult { calculation: "bmi" })
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! English (United States) catalog.
+
+use super::Message;
+use crate::assessment::{Formula, RiskLevel, Standard, Unit};
+use crate::bmi::calculator::BmiCategory;
+use crate::bmi::index::PonderalCategory;
+use crate::body_fat::composition::FfmiCategory;
+use crate::bmi::pediatric::{GrowthReference, PediatricCategory};
+use crate::bmi::standard::BmiReferenceStandard;
+use crate::body_fat::{BodyFatAgeCategory, BodyFatSexCategory};
+use crate::common::sex::Sex;
+use crate::metabolism::activity::ActivityLevel;
+use crate::metabolism::tmb::TmbCategory;
+use crate::planning::goal::{DietStyle, Goal};
+use crate::whr::shape::AbsiRiskCategory;
+use crate::whr::waist::{IdfPopulation, WaistRiskCategory, WaistStandard, WhtrCategory};
+
+pub fn text(message: Message) -> &'static str {
+    match message {
+        Message::Bmi(category) => match category {
+            BmiCategory::Underweight => "Underweight",
+            BmiCategory::NormalWeight => "Normal weight",
+            BmiCategory::Overweight => "Overweight",
+            BmiCategory::ObesityGrade1 => "Obesity Grade 1",
+            BmiCategory::ObesityGrade2 => "Obesity Grade 2",
+            BmiCategory::ObesityGrade3 => "Obesity Grade 3 (morbid)",
+        },
+        Message::Tmb(category) => match category {
+            TmbCategory::VeryLow => "Very low",
+            TmbCategory::Low => "Low",
+            TmbCategory::Normal => "Normal",
+            TmbCategory::High => "High",
+            TmbCategory::VeryHigh => "Very high",
+        },
+        Message::BodyFatBySex(category) => match category {
+            BodyFatSexCategory::Essential => "Essential to life",
+            BodyFatSexCategory::Athlete => "Athlete",
+            BodyFatSexCategory::Fitness => "Fitness",
+            BodyFatSexCategory::Acceptable => "Acceptable",
+            BodyFatSexCategory::Obesity => "Obesity",
+        },
+        Message::BodyFatByAge(category) => match category {
+            BodyFatAgeCategory::Low => "Low",
+            BodyFatAgeCategory::Normal => "Normal",
+            BodyFatAgeCategory::High => "High",
+            BodyFatAgeCategory::VeryHigh => "Very High",
+        },
+        Message::Risk(risk) => match risk {
+            RiskLevel::Low => "Low",
+            RiskLevel::Moderate => "Moderate",
+            RiskLevel::High => "High",
+            RiskLevel::VeryHigh => "Very high",
+        },
+        Message::Formula(formula) => match formula {
+            Formula::Quetelet => "Quetelet",
+            Formula::BmiPrime => "BMI Prime",
+            Formula::Trefethen => "Trefethen (2013)",
+            Formula::Ponderal => "Rohrer (Ponderal Index)",
+            Formula::HarrisBenedict => "Harris-Benedict (revised)",
+            Formula::MifflinStJeor => "Mifflin-St Jeor",
+            Formula::KatchMcArdle => "Katch-McArdle",
+            Formula::Cunningham => "Cunningham",
+            Formula::Deurenberg => "Deurenberg",
+            Formula::JacksonPollock3 => "Jackson-Pollock 3-site",
+            Formula::JacksonPollock7 => "Jackson-Pollock 7-site",
+            Formula::DurninWomersley => "Durnin-Womersley",
+            Formula::Parrillo => "Parrillo",
+            Formula::CunBae => "CUN-BAE",
+            Formula::RelativeFatMass => "Relative Fat Mass (RFM)",
+            Formula::Boer => "Boer",
+            Formula::James => "James",
+            Formula::Hume => "Hume",
+            Formula::Mosteller => "Mosteller",
+            Formula::DuBois => "DuBois",
+            Formula::Haycock => "Haycock",
+            Formula::GehanGeorge => "Gehan-George",
+            Formula::Boyd => "Boyd",
+            Formula::UsNavy => "US Navy",
+            Formula::WaistToHip => "Waist-to-hip ratio",
+            Formula::WaistToHeight => "Waist-to-height ratio",
+            Formula::WaistCircumference => "Waist circumference",
+            Formula::Devine => "Devine",
+            Formula::Robinson => "Robinson",
+            Formula::Miller => "Miller",
+            Formula::Hamwi => "Hamwi",
+        },
+        Message::Unit(unit) => match unit {
+            Unit::KilogramsPerSquareMeter => "kg/m²",
+            Unit::KilogramsPerCubicMeter => "kg/m³",
+            Unit::KilocaloriesPerDay => "kcal/day",
+            Unit::Kilograms => "kg",
+            Unit::Centimeters => "cm",
+            Unit::SquareMeters => "m²",
+            Unit::Percent => "%",
+            Unit::Ratio => "",
+        },
+        Message::Sex(sex) => match sex {
+            Sex::Male => "male",
+            Sex::Female => "female",
+        },
+        Message::Activity(level) => match level {
+            ActivityLevel::Sedentary => "Sedentary",
+            ActivityLevel::LightlyActive => "Lightly active",
+            ActivityLevel::ModeratelyActive => "Moderately active",
+            ActivityLevel::VeryActive => "Very active",
+            ActivityLevel::ExtraActive => "Extra active",
+        },
+        Message::Whtr(category) => match category {
+            WhtrCategory::Low => "Below healthy range",
+            WhtrCategory::Healthy => "Healthy",
+            WhtrCategory::IncreasedRisk => "Increased risk",
+            WhtrCategory::HighRisk => "High risk",
+        },
+        Message::WaistRisk(category) => match category {
+            WaistRiskCategory::Normal => "Normal",
+            WaistRiskCategory::Increased => "Increased risk of metabolic complications",
+            WaistRiskCategory::SubstantiallyIncreased => "Substantially increased risk of metabolic complications",
+        },
+        Message::Absi(category) => match category {
+            AbsiRiskCategory::VeryLow => "Very low mortality risk",
+            AbsiRiskCategory::Low => "Low mortality risk",
+            AbsiRiskCategory::Average => "Average mortality risk",
+            AbsiRiskCategory::High => "High mortality risk",
+            AbsiRiskCategory::VeryHigh => "Very high mortality risk",
+        },
+        Message::Ffmi(category) => match category {
+            FfmiCategory::BelowAverage => "Below average",
+            FfmiCategory::Average => "Average",
+            FfmiCategory::AboveAverage => "Above average",
+            FfmiCategory::Excellent => "Excellent",
+            FfmiCategory::Superior => "Superior",
+            FfmiCategory::Exceptional => "Exceptional (rarely reached naturally)",
+        },
+        Message::Ponderal(category) => match category {
+            PonderalCategory::Low => "Low",
+            PonderalCategory::Normal => "Normal",
+            PonderalCategory::High => "High",
+        },
+        Message::Pediatric(category) => match category {
+            PediatricCategory::SevereThinness => "Severe thinness",
+            PediatricCategory::Thinness => "Thinness",
+            PediatricCategory::Normal => "Normal",
+            PediatricCategory::PossibleRiskOfOverweight => "Possible risk of overweight",
+            PediatricCategory::Overweight => "Overweight",
+            PediatricCategory::Obesity => "Obesity",
+        },
+        Message::GrowthReference(reference) => match reference {
+            GrowthReference::Who => "WHO 2006/2007",
+            GrowthReference::Cdc => "CDC 2000",
+        },
+        Message::Standard(Standard::Bmi(standard)) => match standard {
+            BmiReferenceStandard::WhoInternational => "WHO international",
+            BmiReferenceStandard::WhoAsianPacific => "WHO Asian-Pacific",
+            BmiReferenceStandard::WhoExpertConsultation => "WHO expert consultation (Asian populations)",
+            BmiReferenceStandard::Jasso => "JASSO (Japan)",
+        },
+        Message::Standard(Standard::Waist(standard)) => match standard {
+            WaistStandard::Who => "WHO",
+            WaistStandard::Ncep => "NCEP ATP III",
+            WaistStandard::Idf(IdfPopulation::Europid) => "IDF (Europid)",
+            WaistStandard::Idf(IdfPopulation::SouthAsian) => "IDF (South Asian)",
+            WaistStandard::Idf(IdfPopulation::Chinese) => "IDF (Chinese)",
+            WaistStandard::Idf(IdfPopulation::Japanese) => "IDF (Japanese)",
+        },
+        Message::Goal(goal) => match goal {
+            Goal::Lose => "Lose weight",
+            Goal::Maintain => "Maintain weight",
+            Goal::Gain => "Gain weight",
+        },
+        Message::Diet(diet) => match diet {
+            DietStyle::Balanced => "Balanced",
+            DietStyle::LowCarb => "Low-carb",
+            DietStyle::HighProtein => "High-protein",
+            DietStyle::Ketogenic => "Ketogenic",
+        },
+        // Field names are already English identifiers
+        Message::Field(name) => name,
+
+        Message::BmiTitle => "BMI",
+        Message::BmiPrimeTitle => "BMI Prime",
+        Message::NewBmiTitle => "New BMI (Trefethen)",
+        Message::PonderalTitle => "Ponderal Index",
+        Message::TmbTitle => "Basal Metabolic Rate (TMB)",
+        Message::PgcTitle => "Body Fat Percentage (PGC)",
+        Message::PgcComparisonTitle => "Body Fat Percentage (PGC) by method",
+        Message::PgcSpread => "{} - {} ({} percentage points)",
+        Message::SpreadLabel => "Spread",
+        Message::SkippedLabel => "Left out (implausible result)",
+        Message::FatMassLabel => "Fat mass",
+        Message::FatFreeMassLabel => "Fat-free mass",
+        Message::LeanBodyMassLabel => "Lean body mass",
+        Message::FfmiLabel => "Fat-Free Mass Index (FFMI)",
+        Message::NormalizedFfmiLabel => "Normalized FFMI (1.80 m)",
+        Message::MeanLabel => "Mean",
+        Message::WhrTitle => "Waist-to-Hip Ratio (WHR)",
+        Message::WhtrTitle => "Waist-to-Height Ratio (WHtR)",
+        Message::WaistTitle => "Waist circumference",
+        Message::BsaTitle => "Body surface area (BSA)",
+        Message::AbsiLabel => "A Body Shape Index (ABSI)",
+        Message::AbsiWithZScore => "{} (z-score {}: {})",
+        Message::BriLabel => "Body Roundness Index (BRI)",
+        Message::ConicityLabel => "Conicity Index",
+        Message::TdeeTitle => "Total Daily Energy Expenditure (TDEE)",
+        Message::ActivityLabel => "Activity level",
+        Message::GoalWithRate => "{} ({} kg/week)",
+        Message::GoalLabel => "Goal",
+        Message::DietLabel => "Diet",
+        Message::CalorieTargetLabel => "Daily calorie target",
+        Message::ProteinLabel => "Protein",
+        Message::CarbohydrateLabel => "Carbohydrate",
+        Message::FatLabel => "Fat",
+        Message::ProjectionHeader => "Day | Date | Weight (kg) | BMI | PGC (%)",
+        Message::TargetReached => "{} reached on {} (day {})",
+        Message::TargetNotReached => "{} not reached within {} days",
+        Message::ZScoreLabel => "BMI-for-age z-score",
+        Message::ZScoreWithPercentile => "{} (percentile {})",
+        Message::GrowthReferenceLabel => "Growth reference",
+        Message::HealthyWeightRangeLabel => "Healthy weight range",
+        Message::IdealWeightLabel => "Ideal body weight",
+        Message::AdjustedWeightLabel => "Adjusted body weight",
+        Message::ClassificationLabel => "Classification",
+        Message::ReferenceRangeLabel => "Reference range",
+        Message::FormulaLabel => "Formula",
+        Message::RiskLabel => "Risk",
+        Message::StandardLabel => "Standard",
+        Message::BodyFatClassification => "{} (by sex), {} (by age group)",
+        Message::BodyFatClassificationBySex => "{} (by sex)",
+        Message::WhrClassification => "{} cardiovascular risk",
+
+        Message::ErrNotFinite => "{} must be a finite number",
+        Message::ErrNegative => "{} must not be negative (got {})",
+        Message::ErrZero => "{} must be greater than zero",
+        Message::ErrImplausible => "{} of {} is outside the plausible range {}..={}",
+        Message::ErrInvalidResult => "{} produced an invalid result",
+        Message::ErrMissing => "{} is required",
+        Message::ErrUnrecognized => "'{}' is not a valid {}",
+        Message::ErrUnsafeTarget => "a calorie target of {} kcal/day is below the safe minimum of {} kcal/day",
+
+        Message::UsageUnknownCommand => "unknown command '{}'",
+        Message::UsageUnexpectedArgument => "unexpected argument '{}'",
+        Message::UsageMissingValue => "missing value for --{}",
+        Message::UsageRepeatedOption => "--{} given more than once",
+        Message::UsageUnknownOption => "unknown option --{}",
+        Message::UsageMissingOption => "missing required option --{}",
+        Message::UsageUnknownFormat => "unknown format '{}' (expected text or json)",
+        Message::UsageJsonFeature => "JSON output requires building with the `json` feature",
+        Message::UsageRequiresAge => "--{} requires --age",
+        Message::UsageMonthsRange => "--months must be between 0 and 11 (got {})",
+        Message::UsageAdultsOnly => "--{} applies only to adults; use --reference under 20",
+        Message::UsageLeanMassFormula => "--lean-mass applies only to --formula katch-mcardle or cunningham",
+        Message::UsagePopulationIdf => "--population applies only to --standard idf",
+        Message::UsageWaistAndHip => "--waist and --hip must be given together",
+        Message::UsageBatchJson => "batch writes CSV; --format json is not supported",
+
+        Message::MenuTitle => "Request the health check you want to do:",
+        Message::MenuBmi => "1 – BMI",
+        Message::MenuTmb => "2 – TMB",
+        Message::MenuPgc => "3 – Body Fat Percentage (PGC)",
+        Message::MenuWhr => "4 – Waist-to-Hip Ratio (WHR)",
+        Message::MenuTdee => "5 – Total Daily Energy Expenditure (TDEE)",
+        Message::MenuExit => "0 – Exit",
+        Message::PromptWeight => "Please enter your weight in kilograms (e.g., 70.5): ",
+        Message::PromptHeight => "Please enter your height in meters (e.g., 1.75): ",
+        Message::PromptAge => "Please enter your age in years (e.g., 30): ",
+        Message::PromptSex => "Please enter your gender (M/F): ",
+        Message::PromptWaist => "Please enter your waist circumference in centimeters (e.g., 85.0): ",
+        Message::PromptHip => "Please enter your hip circumference in centimeters (e.g., 95.0): ",
+        Message::PromptActivity => "Please enter your activity level:\n\
+             1 – Sedentary (little or no exercise)\n\
+             2 – Lightly active (1-3 days/week)\n\
+             3 – Moderately active (3-5 days/week)\n\
+             4 – Very active (6-7 days/week)\n\
+             5 – Extra active (physical job or training twice a day)",
+        Message::InvalidNumber => "Invalid number. Please enter a number such as 70.5, 70,5 or 175cm:",
+        Message::InvalidWholeNumber => "Invalid number. Please enter a valid whole number:",
+        Message::InvalidSex => "Invalid gender input. Please use 'M' or 'F'.",
+        Message::InvalidActivity => "Invalid activity level. Please enter a number from 1 to 5:",
+        Message::InvalidOption => "Invalid option. Please enter 1, 2, 3, 4, 5, or 0.",
+        Message::InvalidInput => "Invalid input: {}",
+        Message::Exiting => "Exiting application.",
+        Message::BatchSummary => "Processed {} rows ({} with errors)",
+    }
+}
@@ -0,0 +1,329 @@
+//! Internationalization: message catalogs for category labels, reports,
+//! error messages and CLI prompts.
+//!
+//! Every user-facing string is identified by a [`Message`] key and looked up
+//! in the catalog of the selected [`Lang`]. Catalogs live in one file per
+//! language (`en_us.rs`, `pt_br.rs`).
+
+mod en_us;
+mod pt_br;
+
+use std::env;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::assessment::{Formula, RiskLevel, Standard, Unit};
+use crate::bmi::index::PonderalCategory;
+use crate::body_fat::composition::FfmiCategory;
+use crate::bmi::pediatric::{GrowthReference, PediatricCategory};
+use crate::metabolism::activity::ActivityLevel;
+use crate::planning::goal::{DietStyle, Goal};
+use crate::bmi::calculator::BmiCategory;
+use crate::body_fat::{BodyFatAgeCategory, BodyFatSexCategory};
+use crate::common::sex::Sex;
+use crate::error::HealthError;
+use crate::metabolism::tmb::TmbCategory;
+use crate::whr::shape::AbsiRiskCategory;
+use crate::whr::waist::{WaistRiskCategory, WhtrCategory};
+
+/// Supported languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    /// English (United States), the default.
+    #[default]
+    EnUs,
+    /// Brazilian Portuguese.
+    PtBr,
+}
+
+impl Lang {
+    /// Reads the language from `LC_ALL`, `LC_MESSAGES` or `LANG` (in that
+    /// order, as POSIX does), falling back to English.
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Formats a number with `places` decimals and the language's decimal separator.
+    pub fn decimal(&self, value: f32, places: usize) -> String {
+        let mut text = format!("{:.*}", places, value);
+        // Values that round to zero should not print as "-0.00"
+        if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.') {
+            text.remove(0);
+        }
+        match self {
+            Lang::EnUs => text,
+            Lang::PtBr => text.replace('.', ","),
+        }
+    }
+}
+
+impl FromStr for Lang {
+    type Err = HealthError;
+
+    /// Accepts tags such as `pt`, `pt-BR`, `pt_BR.UTF-8`, `en` or `en_US`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let tag = input.trim().to_lowercase();
+        let language = tag.split(['-', '_', '.', '@']).next().unwrap_or("");
+        match language {
+            "pt" => Ok(Lang::PtBr),
+            "en" => Ok(Lang::EnUs),
+            _ => Err(HealthError::Unrecognized {
+                field: "language",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Key of a translatable message.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Message {
+    // Category and enum labels
+    Bmi(BmiCategory),
+    Tmb(TmbCategory),
+    BodyFatBySex(BodyFatSexCategory),
+    BodyFatByAge(BodyFatAgeCategory),
+    Risk(RiskLevel),
+    Formula(Formula),
+    Unit(Unit),
+    Sex(Sex),
+    Activity(ActivityLevel),
+    Pediatric(PediatricCategory),
+    Ponderal(PonderalCategory),
+    Ffmi(FfmiCategory),
+    Whtr(WhtrCategory),
+    WaistRisk(WaistRiskCategory),
+    Absi(AbsiRiskCategory),
+    GrowthReference(GrowthReference),
+    Standard(Standard),
+    Goal(Goal),
+    Diet(DietStyle),
+    /// Name of an input or calculation, as used in error messages.
+    Field(&'static str),
+
+    // Report layout
+    BmiTitle,
+    BmiPrimeTitle,
+    NewBmiTitle,
+    PonderalTitle,
+    TmbTitle,
+    PgcTitle,
+    /// Heading of the body fat comparison
+    PgcComparisonTitle,
+    /// `{min} - {max} ({spread} percentage points)`
+    PgcSpread,
+    SpreadLabel,
+    /// Estimators left out because their result was implausible
+    SkippedLabel,
+    FatMassLabel,
+    FatFreeMassLabel,
+    LeanBodyMassLabel,
+    FfmiLabel,
+    NormalizedFfmiLabel,
+    MeanLabel,
+    WhrTitle,
+    WhtrTitle,
+    WaistTitle,
+    BsaTitle,
+    AbsiLabel,
+    /// `{absi} (z-score {z}: {category})`
+    AbsiWithZScore,
+    BriLabel,
+    ConicityLabel,
+    TdeeTitle,
+    ActivityLabel,
+    /// `{goal} ({rate} kg/week)`
+    GoalWithRate,
+    GoalLabel,
+    DietLabel,
+    CalorieTargetLabel,
+    ProteinLabel,
+    CarbohydrateLabel,
+    FatLabel,
+    /// Column headings of the projection table
+    ProjectionHeader,
+    /// `{category} reached on {date} (day {day})`
+    TargetReached,
+    /// `{category} not reached within {days} days`
+    TargetNotReached,
+    ZScoreLabel,
+    /// `{z-score} ({percentile} percentile)`
+    ZScoreWithPercentile,
+    GrowthReferenceLabel,
+    HealthyWeightRangeLabel,
+    IdealWeightLabel,
+    AdjustedWeightLabel,
+    ClassificationLabel,
+    ReferenceRangeLabel,
+    FormulaLabel,
+    RiskLabel,
+    StandardLabel,
+    /// `{by sex} (by sex), {by age} (by age group)`
+    BodyFatClassification,
+    /// `{by sex} (by sex)`, under 20
+    BodyFatClassificationBySex,
+    /// `{risk} cardiovascular risk`
+    WhrClassification,
+
+    // Error messages (placeholders follow `HealthError`'s fields)
+    ErrNotFinite,
+    ErrNegative,
+    ErrZero,
+    ErrImplausible,
+    ErrInvalidResult,
+    ErrMissing,
+    ErrUnrecognized,
+    ErrUnsafeTarget,
+
+    // Command-line usage errors
+    /// `unknown command '{command}'`
+    UsageUnknownCommand,
+    /// `unexpected argument '{argument}'`
+    UsageUnexpectedArgument,
+    /// `missing value for --{flag}`
+    UsageMissingValue,
+    /// `--{flag} given more than once`
+    UsageRepeatedOption,
+    /// `unknown option --{flag}`
+    UsageUnknownOption,
+    /// `missing required option --{flag}`
+    UsageMissingOption,
+    /// `unknown format '{format}' (expected text or json)`
+    UsageUnknownFormat,
+    UsageJsonFeature,
+    /// `--{flag} requires --age`
+    UsageRequiresAge,
+    /// `--months must be between 0 and 11 (got {months})`
+    UsageMonthsRange,
+    /// `--{flag} applies only to adults; use --reference under 20`
+    UsageAdultsOnly,
+    UsageLeanMassFormula,
+    UsagePopulationIdf,
+    UsageWaistAndHip,
+    UsageBatchJson,
+
+    // Interactive prompts
+    MenuTitle,
+    MenuBmi,
+    MenuTmb,
+    MenuPgc,
+    MenuWhr,
+    MenuTdee,
+    MenuExit,
+    PromptWeight,
+    PromptHeight,
+    PromptAge,
+    PromptSex,
+    PromptWaist,
+    PromptHip,
+    PromptActivity,
+    InvalidNumber,
+    InvalidWholeNumber,
+    InvalidSex,
+    InvalidActivity,
+    InvalidOption,
+    /// `Invalid input: {error}`
+    InvalidInput,
+    Exiting,
+    /// `Processed {rows} rows ({errors} with errors)`
+    BatchSummary,
+}
+
+/// Looks up the text of `message` in the catalog of `lang`.
+pub fn text(lang: Lang, message: Message) -> &'static str {
+    match lang {
+        Lang::EnUs => en_us::text(message),
+        Lang::PtBr => pt_br::text(message),
+    }
+}
+
+/// Looks up a message template and fills its `{}` placeholders in order.
+pub fn format(lang: Lang, message: Message, args: &[&dyn Display]) -> String {
+    let template = text(lang, message);
+    let mut output = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut pieces = template.split("{}").peekable();
+    while let Some(piece) = pieces.next() {
+        output.push_str(piece);
+        if pieces.peek().is_some() {
+            if let Some(arg) = args.next() {
+                output.push_str(&arg.to_string());
+            }
+        }
+    }
+    output
+}
+
+/// Describes a validation error in the given language.
+pub fn describe_error(lang: Lang, err: &HealthError) -> String {
+    let field = |name: &'static str| text(lang, Message::Field(name));
+    match err {
+        HealthError::NotFinite { field: name } => format(lang, Message::ErrNotFinite, &[&field(name)]),
+        HealthError::Negative { field: name, value } => {
+            format(lang, Message::ErrNegative, &[&field(name), value])
+        }
+        HealthError::Zero { field: name } => format(lang, Message::ErrZero, &[&field(name)]),
+        HealthError::Implausible { field: name, value, min, max } => {
+            format(lang, Message::ErrImplausible, &[&field(name), value, min, max])
+        }
+        HealthError::InvalidResult { calculation } => {
+            format(lang, Message::ErrInvalidResult, &[&field(calculation)])
+        }
+        HealthError::Missing { field: name } => format(lang, Message::ErrMissing, &[&field(name)]),
+        HealthError::Unrecognized { field: name, input } => {
+            format(lang, Message::ErrUnrecognized, &[input, &field(name)])
+        }
+        HealthError::UnsafeTarget { target, minimum } => {
+            format(lang, Message::ErrUnsafeTarget, &[&lang.decimal(*target, 0), &lang.decimal(*minimum, 0)])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_language_tags() {
+        assert_eq!("pt-BR".parse::<Lang>(), Ok(Lang::PtBr));
+        assert_eq!("pt_BR.UTF-8".parse::<Lang>(), Ok(Lang::PtBr));
+        assert_eq!("en_US".parse::<Lang>(), Ok(Lang::EnUs));
+        assert!("C".parse::<Lang>().is_err());
+    }
+
+    #[test]
+    fn test_category_labels() {
+        assert_eq!(text(Lang::EnUs, Message::Bmi(BmiCategory::NormalWeight)), "Normal weight");
+        assert_eq!(text(Lang::PtBr, Message::Bmi(BmiCategory::NormalWeight)), "Peso normal");
+        assert_eq!(text(Lang::PtBr, Message::Risk(RiskLevel::VeryHigh)), "Muito alto");
+    }
+
+    #[test]
+    fn test_format_placeholders() {
+        assert_eq!(format(Lang::EnUs, Message::BatchSummary, &[&3, &1]), "Processed 3 rows (1 with errors)");
+        assert_eq!(format(Lang::PtBr, Message::BatchSummary, &[&3, &1]), "3 linhas processadas (1 com erros)");
+    }
+
+    #[test]
+    fn test_describe_error() {
+        let err = HealthError::Zero { field: "height" };
+        assert_eq!(describe_error(Lang::EnUs, &err), "height must be greater than zero");
+        assert_eq!(describe_error(Lang::PtBr, &err), "altura deve ser maior que zero");
+        let err = HealthError::InvalidResult { calculation: "bri" };
+        assert_eq!(
+            describe_error(Lang::PtBr, &err),
+            "índice de arredondamento corporal (BRI) produziu um resultado inválido"
+        );
+    }
+
+    #[test]
+    fn test_decimal_separator() {
+        assert_eq!(Lang::EnUs.decimal(22.857, 2), "22.86");
+        assert_eq!(Lang::PtBr.decimal(22.857, 2), "22,86");
+    }
+}
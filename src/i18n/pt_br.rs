@@ -0,0 +1,340 @@
+//! Catálogo em português do Brasil.
+
+use super::Message;
+use crate::assessment::{Formula, RiskLevel, Standard, Unit};
+use crate::bmi::calculator::BmiCategory;
+use crate::bmi::index::PonderalCategory;
+use crate::body_fat::composition::FfmiCategory;
+use crate::bmi::pediatric::{GrowthReference, PediatricCategory};
+use crate::bmi::standard::BmiReferenceStandard;
+use crate::body_fat::{BodyFatAgeCategory, BodyFatSexCategory};
+use crate::common::sex::Sex;
+use crate::metabolism::activity::ActivityLevel;
+use crate::metabolism::tmb::TmbCategory;
+use crate::planning::goal::{DietStyle, Goal};
+use crate::whr::shape::AbsiRiskCategory;
+use crate::whr::waist::{IdfPopulation, WaistRiskCategory, WaistStandard, WhtrCategory};
+
+pub fn text(message: Message) -> &'static str {
+    match message {
+        Message::Bmi(category) => match category {
+            BmiCategory::Underweight => "Abaixo do peso",
+            BmiCategory::NormalWeight => "Peso normal",
+            BmiCategory::Overweight => "Sobrepeso",
+            BmiCategory::ObesityGrade1 => "Obesidade grau 1",
+            BmiCategory::ObesityGrade2 => "Obesidade grau 2",
+            BmiCategory::ObesityGrade3 => "Obesidade grau 3 (mórbida)",
+        },
+        Message::Tmb(category) => match category {
+            TmbCategory::VeryLow => "Muito baixa",
+            TmbCategory::Low => "Baixa",
+            TmbCategory::Normal => "Normal",
+            TmbCategory::High => "Alta",
+            TmbCategory::VeryHigh => "Muito alta",
+        },
+        Message::BodyFatBySex(category) => match category {
+            BodyFatSexCategory::Essential => "Gordura essencial",
+            BodyFatSexCategory::Athlete => "Atleta",
+            BodyFatSexCategory::Fitness => "Boa forma",
+            BodyFatSexCategory::Acceptable => "Aceitável",
+            BodyFatSexCategory::Obesity => "Obesidade",
+        },
+        Message::BodyFatByAge(category) => match category {
+            BodyFatAgeCategory::Low => "Baixo",
+            BodyFatAgeCategory::Normal => "Normal",
+            BodyFatAgeCategory::High => "Alto",
+            BodyFatAgeCategory::VeryHigh => "Muito alto",
+        },
+        Message::Risk(risk) => match risk {
+            RiskLevel::Low => "Baixo",
+            RiskLevel::Moderate => "Moderado",
+            RiskLevel::High => "Alto",
+            RiskLevel::VeryHigh => "Muito alto",
+        },
+        Message::Formula(formula) => match formula {
+            Formula::Quetelet => "Quetelet",
+            Formula::BmiPrime => "IMC Prime",
+            Formula::Trefethen => "Trefethen (2013)",
+            Formula::Ponderal => "Rohrer (Índice Ponderal)",
+            Formula::HarrisBenedict => "Harris-Benedict (revisada)",
+            Formula::MifflinStJeor => "Mifflin-St Jeor",
+            Formula::KatchMcArdle => "Katch-McArdle",
+            Formula::Cunningham => "Cunningham",
+            Formula::Deurenberg => "Deurenberg",
+            Formula::JacksonPollock3 => "Jackson-Pollock 3 dobras",
+            Formula::JacksonPollock7 => "Jackson-Pollock 7 dobras",
+            Formula::DurninWomersley => "Durnin-Womersley",
+            Formula::Parrillo => "Parrillo",
+            Formula::CunBae => "CUN-BAE",
+            Formula::RelativeFatMass => "Massa Gorda Relativa (RFM)",
+            Formula::Boer => "Boer",
+            Formula::James => "James",
+            Formula::Hume => "Hume",
+            Formula::Mosteller => "Mosteller",
+            Formula::DuBois => "DuBois",
+            Formula::Haycock => "Haycock",
+            Formula::GehanGeorge => "Gehan-George",
+            Formula::Boyd => "Boyd",
+            Formula::UsNavy => "Marinha dos EUA (US Navy)",
+            Formula::WaistToHip => "Relação cintura-quadril",
+            Formula::WaistToHeight => "Relação cintura-estatura",
+            Formula::WaistCircumference => "Circunferência da cintura",
+            Formula::Devine => "Devine",
+            Formula::Robinson => "Robinson",
+            Formula::Miller => "Miller",
+            Formula::Hamwi => "Hamwi",
+        },
+        Message::Unit(unit) => match unit {
+            Unit::KilogramsPerSquareMeter => "kg/m²",
+            Unit::KilogramsPerCubicMeter => "kg/m³",
+            Unit::KilocaloriesPerDay => "kcal/dia",
+            Unit::Kilograms => "kg",
+            Unit::Centimeters => "cm",
+            Unit::SquareMeters => "m²",
+            Unit::Percent => "%",
+            Unit::Ratio => "",
+        },
+        Message::Sex(sex) => match sex {
+            Sex::Male => "masculino",
+            Sex::Female => "feminino",
+        },
+        Message::Activity(level) => match level {
+            ActivityLevel::Sedentary => "Sedentário",
+            ActivityLevel::LightlyActive => "Levemente ativo",
+            ActivityLevel::ModeratelyActive => "Moderadamente ativo",
+            ActivityLevel::VeryActive => "Muito ativo",
+            ActivityLevel::ExtraActive => "Extremamente ativo",
+        },
+        Message::Whtr(category) => match category {
+            WhtrCategory::Low => "Abaixo da faixa saudável",
+            WhtrCategory::Healthy => "Saudável",
+            WhtrCategory::IncreasedRisk => "Risco aumentado",
+            WhtrCategory::HighRisk => "Risco alto",
+        },
+        Message::WaistRisk(category) => match category {
+            WaistRiskCategory::Normal => "Normal",
+            WaistRiskCategory::Increased => "Risco aumentado de complicações metabólicas",
+            WaistRiskCategory::SubstantiallyIncreased => "Risco muito aumentado de complicações metabólicas",
+        },
+        Message::Absi(category) => match category {
+            AbsiRiskCategory::VeryLow => "Risco de mortalidade muito baixo",
+            AbsiRiskCategory::Low => "Risco de mortalidade baixo",
+            AbsiRiskCategory::Average => "Risco de mortalidade médio",
+            AbsiRiskCategory::High => "Risco de mortalidade alto",
+            AbsiRiskCategory::VeryHigh => "Risco de mortalidade muito alto",
+        },
+        Message::Ffmi(category) => match category {
+            FfmiCategory::BelowAverage => "Abaixo da média",
+            FfmiCategory::Average => "Média",
+            FfmiCategory::AboveAverage => "Acima da média",
+            FfmiCategory::Excellent => "Excelente",
+            FfmiCategory::Superior => "Superior",
+            FfmiCategory::Exceptional => "Excepcional (raramente atingido naturalmente)",
+        },
+        Message::Ponderal(category) => match category {
+            PonderalCategory::Low => "Baixo",
+            PonderalCategory::Normal => "Normal",
+            PonderalCategory::High => "Alto",
+        },
+        Message::Pediatric(category) => match category {
+            PediatricCategory::SevereThinness => "Magreza acentuada",
+            PediatricCategory::Thinness => "Magreza",
+            PediatricCategory::Normal => "Eutrofia",
+            PediatricCategory::PossibleRiskOfOverweight => "Risco de sobrepeso",
+            PediatricCategory::Overweight => "Sobrepeso",
+            PediatricCategory::Obesity => "Obesidade",
+        },
+        Message::GrowthReference(reference) => match reference {
+            GrowthReference::Who => "OMS 2006/2007",
+            GrowthReference::Cdc => "CDC 2000",
+        },
+        Message::Standard(Standard::Bmi(standard)) => match standard {
+            BmiReferenceStandard::WhoInternational => "OMS internacional",
+            BmiReferenceStandard::WhoAsianPacific => "OMS Ásia-Pacífico",
+            BmiReferenceStandard::WhoExpertConsultation => "Consulta de especialistas da OMS (populações asiáticas)",
+            BmiReferenceStandard::Jasso => "JASSO (Japão)",
+        },
+        Message::Standard(Standard::Waist(standard)) => match standard {
+            WaistStandard::Who => "OMS",
+            WaistStandard::Ncep => "NCEP ATP III",
+            WaistStandard::Idf(IdfPopulation::Europid) => "IDF (europeus)",
+            WaistStandard::Idf(IdfPopulation::SouthAsian) => "IDF (sul-asiáticos)",
+            WaistStandard::Idf(IdfPopulation::Chinese) => "IDF (chineses)",
+            WaistStandard::Idf(IdfPopulation::Japanese) => "IDF (japoneses)",
+        },
+        Message::Goal(goal) => match goal {
+            Goal::Lose => "Perder peso",
+            Goal::Maintain => "Manter o peso",
+            Goal::Gain => "Ganhar peso",
+        },
+        Message::Diet(diet) => match diet {
+            DietStyle::Balanced => "Equilibrada",
+            DietStyle::LowCarb => "Low-carb",
+            DietStyle::HighProtein => "Hiperproteica",
+            DietStyle::Ketogenic => "Cetogênica",
+        },
+        Message::Field(name) => match name {
+            "weight" => "peso",
+            "height" => "altura",
+            "age" => "idade",
+            "birth date" => "data de nascimento",
+            "sex" => "sexo",
+            "waist" => "cintura",
+            "hip" => "quadril",
+            "neck" => "pescoço",
+            "date" => "data",
+            "language" => "idioma",
+            "bmi" => "IMC",
+            "tmb" => "TMB",
+            "pgc" => "PGC",
+            "whr" => "RCQ",
+            "whtr" => "RCEst",
+            "bsa" => "SC",
+            "bsa formula" => "fórmula de superfície corporal",
+            "absi" => "índice de forma corporal (ABSI)",
+            "bri" => "índice de arredondamento corporal (BRI)",
+            "conicity index" => "índice de conicidade",
+            "waist standard" => "padrão de cintura",
+            "population" => "população",
+            "tdee" => "GET",
+            "activity level" => "nível de atividade",
+            "goal" => "objetivo",
+            "diet" => "dieta",
+            "weekly rate" => "ritmo semanal",
+            "calorie target" => "meta calórica",
+            "daily intake" => "ingestão diária",
+            "days" => "dias",
+            "bmi category" => "categoria de IMC",
+            "age in months" => "idade em meses",
+            "z-score" => "escore z",
+            "growth reference" => "referência de crescimento",
+            "bmi standard" => "padrão de IMC",
+            "ideal weight" => "peso ideal",
+            "bmi index" => "índice de massa corporal",
+            "bmi prime" => "IMC Prime",
+            "new bmi" => "novo IMC",
+            "ponderal index" => "índice ponderal",
+            "lean mass" => "massa magra",
+            "lean mass formula" => "fórmula de massa magra",
+            "ffmi" => "FFMI",
+            "formula" => "fórmula",
+            "method" => "método",
+            "density equation" => "equação de densidade",
+            "chest skinfold" => "dobra peitoral",
+            "midaxillary skinfold" => "dobra axilar média",
+            "abdominal skinfold" => "dobra abdominal",
+            "suprailiac skinfold" => "dobra suprailíaca",
+            "thigh skinfold" => "dobra da coxa",
+            "triceps skinfold" => "dobra tricipital",
+            "biceps skinfold" => "dobra bicipital",
+            "subscapular skinfold" => "dobra subescapular",
+            "lower back skinfold" => "dobra lombar",
+            "calf skinfold" => "dobra da panturrilha",
+            other => other,
+        },
+
+        Message::BmiTitle => "IMC",
+        Message::BmiPrimeTitle => "IMC Prime",
+        Message::NewBmiTitle => "Novo IMC (Trefethen)",
+        Message::PonderalTitle => "Índice Ponderal",
+        Message::TmbTitle => "Taxa Metabólica Basal (TMB)",
+        Message::PgcTitle => "Percentual de Gordura Corporal (PGC)",
+        Message::PgcComparisonTitle => "Percentual de Gordura Corporal (PGC) por método",
+        Message::PgcSpread => "{} - {} ({} pontos percentuais)",
+        Message::SpreadLabel => "Variação",
+        Message::SkippedLabel => "Descartados (resultado implausível)",
+        Message::FatMassLabel => "Massa gorda",
+        Message::FatFreeMassLabel => "Massa livre de gordura",
+        Message::LeanBodyMassLabel => "Massa magra",
+        Message::FfmiLabel => "Índice de Massa Livre de Gordura (FFMI)",
+        Message::NormalizedFfmiLabel => "FFMI normalizado (1,80 m)",
+        Message::MeanLabel => "Média",
+        Message::WhrTitle => "Relação Cintura-Quadril (RCQ)",
+        Message::WhtrTitle => "Relação Cintura-Estatura (RCEst)",
+        Message::WaistTitle => "Circunferência da cintura",
+        Message::BsaTitle => "Superfície corporal (SC)",
+        Message::AbsiLabel => "Índice de Forma Corporal (ABSI)",
+        Message::AbsiWithZScore => "{} (escore z {}: {})",
+        Message::BriLabel => "Índice de Arredondamento Corporal (BRI)",
+        Message::ConicityLabel => "Índice de Conicidade",
+        Message::TdeeTitle => "Gasto Energético Total (GET)",
+        Message::ActivityLabel => "Nível de atividade",
+        Message::GoalWithRate => "{} ({} kg/semana)",
+        Message::GoalLabel => "Objetivo",
+        Message::DietLabel => "Dieta",
+        Message::CalorieTargetLabel => "Meta calórica diária",
+        Message::ProteinLabel => "Proteína",
+        Message::CarbohydrateLabel => "Carboidrato",
+        Message::FatLabel => "Gordura",
+        Message::ProjectionHeader => "Dia | Data | Peso (kg) | IMC | PGC (%)",
+        Message::TargetReached => "{} atingido em {} (dia {})",
+        Message::TargetNotReached => "{} não atingido em {} dias",
+        Message::ZScoreLabel => "Escore-z de IMC para idade",
+        Message::ZScoreWithPercentile => "{} (percentil {})",
+        Message::GrowthReferenceLabel => "Referência de crescimento",
+        Message::HealthyWeightRangeLabel => "Faixa de peso saudável",
+        Message::IdealWeightLabel => "Peso ideal",
+        Message::AdjustedWeightLabel => "Peso ajustado",
+        Message::ClassificationLabel => "Classificação",
+        Message::ReferenceRangeLabel => "Faixa de referência",
+        Message::FormulaLabel => "Fórmula",
+        Message::RiskLabel => "Risco",
+        Message::StandardLabel => "Padrão",
+        Message::BodyFatClassification => "{} (por sexo), {} (por faixa etária)",
+        Message::BodyFatClassificationBySex => "{} (por sexo)",
+        Message::WhrClassification => "Risco cardiovascular {}",
+
+        Message::ErrNotFinite => "{} deve ser um número finito",
+        Message::ErrNegative => "{} não pode ser negativo (recebido {})",
+        Message::ErrZero => "{} deve ser maior que zero",
+        Message::ErrImplausible => "{} de {} está fora da faixa plausível {}..={}",
+        Message::ErrInvalidResult => "{} produziu um resultado inválido",
+        Message::ErrMissing => "{} é obrigatório",
+        Message::ErrUnrecognized => "'{}' não é um valor válido para {}",
+        Message::ErrUnsafeTarget => "uma meta de {} kcal/dia está abaixo do mínimo seguro de {} kcal/dia",
+
+        Message::UsageUnknownCommand => "comando desconhecido '{}'",
+        Message::UsageUnexpectedArgument => "argumento inesperado '{}'",
+        Message::UsageMissingValue => "falta o valor de --{}",
+        Message::UsageRepeatedOption => "--{} informado mais de uma vez",
+        Message::UsageUnknownOption => "opção desconhecida --{}",
+        Message::UsageMissingOption => "falta a opção obrigatória --{}",
+        Message::UsageUnknownFormat => "formato desconhecido '{}' (use text ou json)",
+        Message::UsageJsonFeature => "a saída JSON exige compilar com o recurso `json`",
+        Message::UsageRequiresAge => "--{} exige --age",
+        Message::UsageMonthsRange => "--months deve estar entre 0 e 11 (recebido {})",
+        Message::UsageAdultsOnly => "--{} vale apenas para adultos; use --reference abaixo de 20 anos",
+        Message::UsageLeanMassFormula => "--lean-mass vale apenas para --formula katch-mcardle ou cunningham",
+        Message::UsagePopulationIdf => "--population vale apenas para --standard idf",
+        Message::UsageWaistAndHip => "--waist e --hip devem ser informados juntos",
+        Message::UsageBatchJson => "batch grava CSV; --format json não é suportado",
+
+        Message::MenuTitle => "Escolha a avaliação de saúde que deseja fazer:",
+        Message::MenuBmi => "1 – IMC",
+        Message::MenuTmb => "2 – TMB",
+        Message::MenuPgc => "3 – Percentual de Gordura Corporal (PGC)",
+        Message::MenuWhr => "4 – Relação Cintura-Quadril (RCQ)",
+        Message::MenuTdee => "5 – Gasto Energético Total (GET)",
+        Message::MenuExit => "0 – Sair",
+        Message::PromptWeight => "Informe seu peso em quilogramas (ex.: 70,5): ",
+        Message::PromptHeight => "Informe sua altura em metros (ex.: 1,75): ",
+        Message::PromptAge => "Informe sua idade em anos (ex.: 30): ",
+        Message::PromptSex => "Informe seu sexo (M/F): ",
+        Message::PromptWaist => "Informe a circunferência da cintura em centímetros (ex.: 85,0): ",
+        Message::PromptHip => "Informe a circunferência do quadril em centímetros (ex.: 95,0): ",
+        Message::PromptActivity => "Informe seu nível de atividade física:\n\
+             1 – Sedentário (pouco ou nenhum exercício)\n\
+             2 – Levemente ativo (1-3 dias/semana)\n\
+             3 – Moderadamente ativo (3-5 dias/semana)\n\
+             4 – Muito ativo (6-7 dias/semana)\n\
+             5 – Extremamente ativo (trabalho físico ou treino duas vezes ao dia)",
+        Message::InvalidNumber => "Número inválido. Informe um número como 70,5 ou 175cm:",
+        Message::InvalidWholeNumber => "Número inválido. Informe um número inteiro válido:",
+        Message::InvalidSex => "Sexo inválido. Use 'M' ou 'F'.",
+        Message::InvalidActivity => "Nível de atividade inválido. Informe um número de 1 a 5:",
+        Message::InvalidOption => "Opção inválida. Digite 1, 2, 3, 4, 5 ou 0.",
+        Message::InvalidInput => "Entrada inválida: {}",
+        Message::Exiting => "Encerrando o aplicativo.",
+        Message::BatchSummary => "{} linhas processadas ({} com erros)",
+    }
+}
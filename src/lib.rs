@@ -1,4 +1,14 @@
 pub mod bmi;
 pub mod metabolism;
+pub mod planning;
 pub mod whr;
 pub mod body_fat;// 
+pub mod common;
+pub mod error;
+pub mod units;
+pub mod parse;
+pub mod assessment;
+pub mod report;
+pub mod batch;
+pub mod i18n;
+pub mod bsa;
@@ -0,0 +1,84 @@
+//! Physical activity level of a person, used to scale the basal metabolic rate.
+
+use std::str::FromStr;
+
+use crate::error::HealthError;
+use crate::i18n::Message;
+
+/// Habitual physical activity level, from sedentary to extra active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActivityLevel {
+    Sedentary,
+    LightlyActive,
+    ModeratelyActive,
+    VeryActive,
+    ExtraActive,
+}
+
+impl ActivityLevel {
+    /// Every level, from least to most active.
+    pub const ALL: [ActivityLevel; 5] = [
+        ActivityLevel::Sedentary,
+        ActivityLevel::LightlyActive,
+        ActivityLevel::ModeratelyActive,
+        ActivityLevel::VeryActive,
+        ActivityLevel::ExtraActive,
+    ];
+
+    /// Physical activity level (PAL) multiplier applied to the TMB.
+    pub fn multiplier(&self) -> f32 {
+        match self {
+            ActivityLevel::Sedentary => 1.2,
+            ActivityLevel::LightlyActive => 1.375,
+            ActivityLevel::ModeratelyActive => 1.55,
+            ActivityLevel::VeryActive => 1.725,
+            ActivityLevel::ExtraActive => 1.9,
+        }
+    }
+
+    /// Catalog key of the level's label.
+    pub fn message(&self) -> Message {
+        Message::Activity(*self)
+    }
+}
+
+impl FromStr for ActivityLevel {
+    type Err = HealthError;
+
+    /// Parses the menu numbers `1`-`5` as well as English and Portuguese names.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "1" | "sedentary" | "sedentario" | "sedentário" => Ok(ActivityLevel::Sedentary),
+            "2" | "light" | "lightlyactive" | "leve" | "poucoativo" => Ok(ActivityLevel::LightlyActive),
+            "3" | "moderate" | "moderatelyactive" | "moderado" | "moderadamenteativo" => Ok(ActivityLevel::ModeratelyActive),
+            "4" | "very" | "veryactive" | "intenso" | "muitoativo" => Ok(ActivityLevel::VeryActive),
+            "5" | "extra" | "extraactive" | "muitointenso" | "extremamenteativo" => Ok(ActivityLevel::ExtraActive),
+            _ => Err(HealthError::Unrecognized {
+                field: "activity level",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipliers_increase_with_activity() {
+        assert_eq!(ActivityLevel::Sedentary.multiplier(), 1.2);
+        assert_eq!(ActivityLevel::ExtraActive.multiplier(), 1.9);
+        assert!(ActivityLevel::ALL.windows(2).all(|pair| pair[0].multiplier() < pair[1].multiplier()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("moderate".parse::<ActivityLevel>(), Ok(ActivityLevel::ModeratelyActive));
+        assert_eq!("Sedentário".parse::<ActivityLevel>(), Ok(ActivityLevel::Sedentary));
+        assert_eq!("5".parse::<ActivityLevel>(), Ok(ActivityLevel::ExtraActive));
+        assert!("couch".parse::<ActivityLevel>().is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Selectable equations for the Basal Metabolic Rate (TMB).
+//!
+//! Harris-Benedict and Mifflin-St Jeor use weight, height, age and sex;
+//! Katch-McArdle and Cunningham use lean body mass only, which makes them
+//! more accurate for muscular people.
+
+use std::str::FromStr;
+
+use crate::assessment::Formula;
+use crate::body_fat::{BodyFatCalculator, BodyFatData};
+use crate::error::{self, HealthError};
+use crate::metabolism::tmb::{Gender, TmbData};
+use crate::units::{Centimeters, Meters};
+
+/// Equation used to estimate the TMB.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BmrFormula {
+    /// Revised Harris-Benedict (Roza & Shizgal, 1984).
+    #[default]
+    HarrisBenedict,
+    /// Mifflin-St Jeor (1990).
+    MifflinStJeor,
+    /// Katch-McArdle: 370 + 21.6 × lean mass (kg).
+    KatchMcArdle { lean_mass: f32 },
+    /// Cunningham (1980): 500 + 22 × lean mass (kg).
+    Cunningham { lean_mass: f32 },
+}
+
+/// Name of a [`BmrFormula`], without the lean mass the last two need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BmrFormulaName {
+    HarrisBenedict,
+    MifflinStJeor,
+    KatchMcArdle,
+    Cunningham,
+}
+
+impl FromStr for BmrFormulaName {
+    type Err = HealthError;
+
+    /// Parses names such as `harris-benedict`, `mifflin`, `katch-mcardle` or `cunningham`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "harrisbenedict" | "harris" | "hb" => Ok(BmrFormulaName::HarrisBenedict),
+            "mifflinstjeor" | "mifflin" | "msj" => Ok(BmrFormulaName::MifflinStJeor),
+            "katchmcardle" | "katch" => Ok(BmrFormulaName::KatchMcArdle),
+            "cunningham" => Ok(BmrFormulaName::Cunningham),
+            _ => Err(HealthError::Unrecognized {
+                field: "formula",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+impl BmrFormulaName {
+    /// Whether the equation is based on lean body mass.
+    pub fn needs_lean_mass(&self) -> bool {
+        matches!(self, BmrFormulaName::KatchMcArdle | BmrFormulaName::Cunningham)
+    }
+
+    /// Builds the formula; `lean_mass` is ignored by the equations that don't use it.
+    pub fn with_lean_mass(&self, lean_mass: f32) -> BmrFormula {
+        match self {
+            BmrFormulaName::HarrisBenedict => BmrFormula::HarrisBenedict,
+            BmrFormulaName::MifflinStJeor => BmrFormula::MifflinStJeor,
+            BmrFormulaName::KatchMcArdle => BmrFormula::KatchMcArdle { lean_mass },
+            BmrFormulaName::Cunningham => BmrFormula::Cunningham { lean_mass },
+        }
+    }
+}
+
+impl BmrFormula {
+    /// Katch-McArdle with lean mass taken from the body fat module's PGC estimate.
+    pub fn katch_mcardle_from_body_fat(data: &BodyFatData) -> Result<Self, HealthError> {
+        Ok(BmrFormula::KatchMcArdle { lean_mass: BodyFatCalculator::try_lean_mass(data)? })
+    }
+
+    /// Cunningham with lean mass taken from the body fat module's PGC estimate.
+    pub fn cunningham_from_body_fat(data: &BodyFatData) -> Result<Self, HealthError> {
+        Ok(BmrFormula::Cunningham { lean_mass: BodyFatCalculator::try_lean_mass(data)? })
+    }
+
+    /// Lean mass used by the equation, if any.
+    pub fn lean_mass(&self) -> Option<f32> {
+        match self {
+            BmrFormula::KatchMcArdle { lean_mass } | BmrFormula::Cunningham { lean_mass } => Some(*lean_mass),
+            _ => None,
+        }
+    }
+
+    /// Formula reported in the assessment.
+    pub fn formula(&self) -> Formula {
+        match self {
+            BmrFormula::HarrisBenedict => Formula::HarrisBenedict,
+            BmrFormula::MifflinStJeor => Formula::MifflinStJeor,
+            BmrFormula::KatchMcArdle { .. } => Formula::KatchMcArdle,
+            BmrFormula::Cunningham { .. } => Formula::Cunningham,
+        }
+    }
+
+    /// Checks that the lean mass, if used, is plausible and below the body weight.
+    pub fn validate(&self, weight: f32) -> Result<(), HealthError> {
+        if let Some(lean_mass) = self.lean_mass() {
+            error::validate_measurement("lean mass", lean_mass, (error::WEIGHT_RANGE_KG.0, weight))?;
+        }
+        Ok(())
+    }
+
+    /// Estimates the TMB in kcal/day.
+    pub fn calculate(&self, data: &TmbData) -> f32 {
+        // Harris-Benedict and Mifflin-St Jeor take height in centimeters
+        let height_cm = Centimeters::from(Meters(data.height)).0;
+        let age = data.age as f32;
+        match (self, data.gender) {
+            (BmrFormula::HarrisBenedict, Gender::Male) => 88.36 + (13.4 * data.weight) + (4.8 * height_cm) - (5.7 * age),
+            (BmrFormula::HarrisBenedict, Gender::Female) => 447.6 + (9.2 * data.weight) + (3.1 * height_cm) - (4.3 * age),
+            (BmrFormula::MifflinStJeor, gender) => {
+                let sex_constant = match gender {
+                    Gender::Male => 5.0,
+                    Gender::Female => -161.0,
+                };
+                (10.0 * data.weight) + (6.25 * height_cm) - (5.0 * age) + sex_constant
+            }
+            (BmrFormula::KatchMcArdle { lean_mass }, _) => 370.0 + 21.6 * lean_mass,
+            (BmrFormula::Cunningham { lean_mass }, _) => 500.0 + 22.0 * lean_mass,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn male() -> TmbData {
+        TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap()
+    }
+
+    #[test]
+    fn test_mifflin_st_jeor() {
+        // 10 × 70 + 6.25 × 175 − 5 × 25 + 5 = 1673.75
+        assert!((BmrFormula::MifflinStJeor.calculate(&male()) - 1673.75).abs() < 0.01);
+        let female = TmbData::new(60.0, 1.65, 30, Gender::Female).unwrap();
+        // 600 + 1031.25 − 150 − 161 = 1320.25
+        assert!((BmrFormula::MifflinStJeor.calculate(&female) - 1320.25).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_lean_mass_equations() {
+        assert!((BmrFormula::KatchMcArdle { lean_mass: 60.0 }.calculate(&male()) - 1666.0).abs() < 0.01);
+        assert!((BmrFormula::Cunningham { lean_mass: 60.0 }.calculate(&male()) - 1820.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_katch_mcardle_from_body_fat() {
+        let data = BodyFatData::new(70.0, 1.75, 25, Gender::Male).unwrap();
+        let pgc = BodyFatCalculator::try_calculate(&data).unwrap();
+        let formula = BmrFormula::katch_mcardle_from_body_fat(&data).unwrap();
+        assert!((formula.lean_mass().unwrap() - 70.0 * (1.0 - pgc / 100.0)).abs() < 1e-3);
+        assert_eq!(formula.formula(), Formula::KatchMcArdle);
+    }
+
+    #[test]
+    fn test_validate_lean_mass_above_weight() {
+        let result = BmrFormula::Cunningham { lean_mass: 80.0 }.validate(70.0);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "lean mass", .. })));
+        assert_eq!(BmrFormula::MifflinStJeor.validate(70.0), Ok(()));
+    }
+
+    #[test]
+    fn test_parse_name() {
+        assert_eq!("Mifflin-St Jeor".parse::<BmrFormulaName>(), Ok(BmrFormulaName::MifflinStJeor));
+        assert_eq!("katch-mcardle".parse::<BmrFormulaName>(), Ok(BmrFormulaName::KatchMcArdle));
+        assert!("bogus".parse::<BmrFormulaName>().is_err());
+        assert!(BmrFormulaName::Cunningham.needs_lean_mass());
+    }
+}
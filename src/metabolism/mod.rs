@@ -1 +1,4 @@
+pub mod activity; // This is needed to expose the activity level
+pub mod bmr; // Selectable TMB equations
 pub mod tmb; // This is needed to expose the tmb module
+pub mod tdee; // Total daily energy expenditure
@@ -0,0 +1,88 @@
+//! Total Daily Energy Expenditure (TDEE): the TMB scaled by the activity level.
+
+use crate::error::{self, HealthError};
+use crate::metabolism::activity::ActivityLevel;
+use crate::metabolism::bmr::BmrFormula;
+use crate::metabolism::tmb::{TmbAssessment, TmbCalculator, TmbData};
+
+/// Basal and total daily energy expenditure of a person.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TdeeResult {
+    /// Assessment of the basal metabolic rate the TDEE is built on.
+    pub tmb: TmbAssessment,
+    pub activity_level: ActivityLevel,
+    /// PAL multiplier applied to the TMB.
+    pub multiplier: f32,
+    /// Total daily energy expenditure, in kcal/day.
+    pub tdee: f32,
+}
+
+pub struct TdeeCalculator; // Namespace for TDEE calculation methods
+
+impl TdeeCalculator {
+    /// Calculates the TDEE from the Harris-Benedict TMB.
+    pub fn calculate(data: &TmbData, activity_level: ActivityLevel) -> f32 {
+        TmbCalculator::calculate(data) * activity_level.multiplier()
+    }
+
+    /// Calculates the TDEE from the TMB of the chosen equation.
+    pub fn calculate_with(data: &TmbData, formula: &BmrFormula, activity_level: ActivityLevel) -> f32 {
+        TmbCalculator::calculate_with(data, formula) * activity_level.multiplier()
+    }
+
+    /// Validates the input and calculates the TDEE, never returning NaN or infinity.
+    pub fn try_calculate(data: &TmbData, activity_level: ActivityLevel) -> Result<f32, HealthError> {
+        let tmb = TmbCalculator::try_calculate(data)?;
+        error::ensure_finite("tdee", tmb * activity_level.multiplier())
+    }
+
+    /// Assesses the TMB and derives the TDEE, using Harris-Benedict.
+    pub fn assess(data: &TmbData, activity_level: ActivityLevel) -> Result<TdeeResult, HealthError> {
+        Self::assess_with(data, &BmrFormula::HarrisBenedict, activity_level)
+    }
+
+    /// Assesses the TMB with the chosen equation and derives the TDEE.
+    pub fn assess_with(
+        data: &TmbData,
+        formula: &BmrFormula,
+        activity_level: ActivityLevel,
+    ) -> Result<TdeeResult, HealthError> {
+        let tmb = TmbCalculator::assess_with(data, formula)?;
+        let multiplier = activity_level.multiplier();
+        let tdee = error::ensure_finite("tdee", tmb.value * multiplier)?;
+        Ok(TdeeResult { tmb, activity_level, multiplier, tdee })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metabolism::tmb::Gender;
+
+    #[test]
+    fn test_calculate() {
+        let data = TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap();
+        // 1723.86 × 1.55 = 2671.98
+        let tdee = TdeeCalculator::calculate(&data, ActivityLevel::ModeratelyActive);
+        assert!((tdee - 2671.98).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_assess_with_formula() {
+        let data = TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap();
+        let result = TdeeCalculator::assess_with(&data, &BmrFormula::MifflinStJeor, ActivityLevel::Sedentary).unwrap();
+        assert!((result.tmb.value - 1673.75).abs() < 0.01);
+        assert!((result.tdee - 2008.5).abs() < 0.01);
+        assert_eq!(result.multiplier, 1.2);
+    }
+
+    #[test]
+    fn test_try_calculate_rejects_invalid_data() {
+        let data = TmbData { weight: 0.0, height: 1.75, age: 25, gender: Gender::Male };
+        assert_eq!(
+            TdeeCalculator::try_calculate(&data, ActivityLevel::Sedentary),
+            Err(HealthError::Zero { field: "weight" })
+        );
+    }
+}
@@ -1,13 +1,18 @@
-/// This module defines the structures and logic for Basal Metabolic Rate (BMR) calculation.
-/// It follows the SOLID principles, especially Single Responsibility and Open/Closed.
+//! This module defines the structures and logic for Basal Metabolic Rate (BMR) calculation.
+//! It follows the SOLID principles, especially Single Responsibility and Open/Closed.
 // src/metabolism/tmb.rs
 
-#[derive(Debug, Clone)]
-pub enum Gender {
-    Male,
-    Female,
-}
+use crate::assessment::{Assessment, Classification, ReferenceRange, RiskLevel, Unit};
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::metabolism::bmr::BmrFormula;
+use crate::units::{Kilograms, Meters};
+
+/// Kept as an alias of the shared [`Sex`](crate::common::sex::Sex) type.
+pub use crate::common::sex::Sex as Gender;
+
 // Represents the data required for TMB calculation
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TmbData {
     pub weight: f32, // em kg
     pub height: f32, // em metros
@@ -15,9 +20,37 @@ pub struct TmbData {
     pub gender: Gender,
 }
 
+impl TmbData {
+    /// Creates validated TMB input, rejecting non-finite, non-positive or implausible values.
+    pub fn new(
+        weight: impl Into<Kilograms>,
+        height: impl Into<Meters>,
+        age: u32,
+        gender: Gender,
+    ) -> Result<Self, HealthError> {
+        let data = TmbData {
+            weight: weight.into().0,
+            height: height.into().0,
+            age,
+            gender,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that weight (kg), height (m) and age are plausible.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("weight", self.weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        error::validate_age(self.age)?;
+        Ok(())
+    }
+}
+
 pub struct TmbCalculator; // This struct serves as a namespace for TMB calculation methods
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TmbCategory {
     VeryLow,
     Low,
@@ -25,18 +58,79 @@ pub enum TmbCategory {
     High,
     VeryHigh,
 }
+
+impl Classification for TmbCategory {
+    fn message(&self) -> Message {
+        Message::Tmb(*self)
+    }
+
+    /// The TMB describes energy needs, not a health risk.
+    fn risk(&self) -> Option<RiskLevel> {
+        None
+    }
+}
+
+/// Typed result of a TMB assessment.
+pub type TmbAssessment = Assessment<TmbCategory>;
 // Represents the categories of TMB based on the calculated value per kg of body weight (WHO guidelines)
 impl TmbCalculator {
+    /// Calculates the TMB with the revised Harris-Benedict equation.
     pub fn calculate(data: &TmbData) -> f32 {
-        match data.gender {
-            Gender::Male => {
-                88.36 + (13.4 * data.weight) + (4.8 * data.height * 100.0) - (5.7 * data.age as f32)
-            }
-            Gender::Female => {
-                447.6 + (9.2 * data.weight) + (3.1 * data.height * 100.0) - (4.3 * data.age as f32)
-            }
+        Self::calculate_with(data, &BmrFormula::HarrisBenedict)
+    }
+
+    /// Calculates the TMB with the chosen equation.
+    pub fn calculate_with(data: &TmbData, formula: &BmrFormula) -> f32 {
+        formula.calculate(data)
+    }
+
+    /// Validates the input and calculates the TMB, never returning NaN or infinity.
+    pub fn try_calculate(data: &TmbData) -> Result<f32, HealthError> {
+        Self::try_calculate_with(data, &BmrFormula::HarrisBenedict)
+    }
+
+    /// Validates the input (and lean mass) and calculates the TMB with the chosen equation.
+    pub fn try_calculate_with(data: &TmbData, formula: &BmrFormula) -> Result<f32, HealthError> {
+        data.validate()?;
+        formula.validate(data.weight)?;
+        error::ensure_finite("tmb", Self::calculate_with(data, formula))
+    }
+
+    /// Classifies the TMB, rejecting a non-finite TMB or an invalid weight.
+    pub fn try_classify(tmb: f32, weight: f32, gender: &Gender) -> Result<TmbCategory, HealthError> {
+        error::ensure_finite("tmb", tmb)?;
+        error::validate_measurement("weight", weight, error::WEIGHT_RANGE_KG)?;
+        Ok(Self::classify(tmb, weight, gender))
+    }
+
+    /// Validates the input, calculates and classifies the TMB as a typed result.
+    /// The reference range is the normal TMB per kg scaled to the person's weight.
+    pub fn assess(data: &TmbData) -> Result<TmbAssessment, HealthError> {
+        Self::assess_with(data, &BmrFormula::HarrisBenedict)
+    }
+
+    /// Like [`assess`](Self::assess), with the chosen equation reported as the formula.
+    pub fn assess_with(data: &TmbData, formula: &BmrFormula) -> Result<TmbAssessment, HealthError> {
+        let tmb = Self::try_calculate_with(data, formula)?;
+        let category = Self::try_classify(tmb, data.weight, &data.gender)?;
+        let (min_per_kg, max_per_kg) = Self::normal_range_per_kg(&data.gender);
+        Ok(Assessment::new(
+            tmb,
+            Unit::KilocaloriesPerDay,
+            category,
+            ReferenceRange::new(Some(min_per_kg * data.weight), Some(max_per_kg * data.weight)),
+            formula.formula(),
+        ))
+    }
+
+    /// Normal TMB range per kg of body weight (kcal/kg/day).
+    pub fn normal_range_per_kg(gender: &Gender) -> (f32, f32) {
+        match gender {
+            Gender::Male => (20.0, 25.0),
+            Gender::Female => (18.0, 23.0),
         }
     }
+
     /// Classifies the TMB based on the calculated value per kg of body weight
     pub fn classify(tmb: f32, weight: f32, gender: &Gender) -> TmbCategory {
         let tmb_per_kg = tmb / weight;
@@ -44,30 +138,25 @@ impl TmbCalculator {
         match gender {
             Gender::Male => match tmb_per_kg {
                 x if x < 15.0 => TmbCategory::VeryLow,
-                x if x > 15.0 && x < 20.0 => TmbCategory::Low,
-                x if x > 20.0 && x < 25.0 => TmbCategory::Normal,
-                x if x > 25.0 && x < 30.0 => TmbCategory::High,
+                x if x < 20.0 => TmbCategory::Low,
+                x if x < 25.0 => TmbCategory::Normal,
+                x if x < 30.0 => TmbCategory::High,
                 _ => TmbCategory::VeryHigh,
             },
             Gender::Female => match tmb_per_kg {
                 x if x < 13.0 => TmbCategory::VeryLow,
-                x if x > 13.0 && x < 18.0 => TmbCategory::Low,
-                x if x > 18.0 && x < 23.0 => TmbCategory::Normal,
-                x if x > 23.0 && x < 28.0 => TmbCategory::High,
+                x if x < 18.0 => TmbCategory::Low,
+                x if x < 23.0 => TmbCategory::Normal,
+                x if x < 28.0 => TmbCategory::High,
                 _ => TmbCategory::VeryHigh,
             },
         }
     }
+
     /// Generates a formatted string with the TMB result and classification
     pub fn evaluation_result(tmb: f32, weight: f32, category: &TmbCategory) -> String {
         let tmb_per_kg = tmb / weight;
-        let classification = match category {
-            TmbCategory::VeryLow => "Very low",
-            TmbCategory::Low => "Low",
-            TmbCategory::Normal => "Normal",
-            TmbCategory::High => "High",
-            TmbCategory::VeryHigh => "Very high",
-        };
+        let classification = category.label();
         // Return a formatted string with the TMB result and classification
         format!(
             "Your Basal Metabolic Rate (TMB) is {:.2} kcal/day.\n\
@@ -80,6 +169,7 @@ impl TmbCalculator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::assessment::Formula;
 
     #[test]
     fn test_calculate_male() {
@@ -129,6 +219,14 @@ mod tests {
         assert_eq!(category, TmbCategory::High);
     }
 
+    #[test]
+    fn test_classify_boundaries_match_reference_range() {
+        // Exactly 20 kcal/kg is the start of the normal range, not a gap
+        assert_eq!(TmbCalculator::classify(1400.0, 70.0, &Gender::Male), TmbCategory::Normal);
+        assert_eq!(TmbCalculator::classify(1750.0, 70.0, &Gender::Male), TmbCategory::High);
+        assert_eq!(TmbCalculator::classify(780.0, 60.0, &Gender::Female), TmbCategory::Low);
+    }
+
     #[test]
     fn test_evaluation_result() {
         let tmb = 1723.86;
@@ -140,4 +238,64 @@ mod tests {
                         Classification: Normal";
         assert_eq!(result, expected);
     }
-} 
+
+    #[test]
+    fn test_assess() {
+        let data = TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap();
+        let assessment = TmbCalculator::assess(&data).unwrap();
+        assert!((assessment.value - 1723.86).abs() < 0.1);
+        assert_eq!(assessment.unit, Unit::KilocaloriesPerDay);
+        assert_eq!(assessment.category, TmbCategory::Normal);
+        assert_eq!(assessment.reference_range, ReferenceRange::new(Some(1400.0), Some(1750.0)));
+        assert_eq!(assessment.formula, Formula::HarrisBenedict);
+        assert_eq!(assessment.risk, None);
+    }
+
+    #[test]
+    fn test_assess_with_mifflin_st_jeor() {
+        let data = TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap();
+        let assessment = TmbCalculator::assess_with(&data, &BmrFormula::MifflinStJeor).unwrap();
+        assert!((assessment.value - 1673.75).abs() < 0.01);
+        assert_eq!(assessment.formula, Formula::MifflinStJeor);
+    }
+
+    #[test]
+    fn test_try_calculate_with_rejects_lean_mass_above_weight() {
+        let data = TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap();
+        let result = TmbCalculator::try_calculate_with(&data, &BmrFormula::KatchMcArdle { lean_mass: 75.0 });
+        assert!(matches!(result, Err(HealthError::Implausible { field: "lean mass", .. })));
+    }
+
+    #[test]
+    fn test_new_with_imperial_units() {
+        use crate::units::{FeetInches, Pounds};
+        let imperial = TmbData::new(Pounds(160.0), FeetInches::new(5, 10.0), 25, Gender::Male).unwrap();
+        let metric = TmbData::new(72.5748, 1.778, 25, Gender::Male).unwrap();
+        let tmb = TmbCalculator::calculate(&imperial);
+        assert!((tmb - TmbCalculator::calculate(&metric)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_new_rejects_implausible_age() {
+        let result = TmbData::new(70.0, 1.75, 200, Gender::Male);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "age", .. })));
+    }
+
+    #[test]
+    fn test_try_calculate_rejects_negative_weight() {
+        let data = TmbData {
+            weight: -70.0,
+            height: 1.75,
+            age: 25,
+            gender: Gender::Male,
+        };
+        let result = TmbCalculator::try_calculate(&data);
+        assert_eq!(result, Err(HealthError::Negative { field: "weight", value: -70.0 }));
+    }
+
+    #[test]
+    fn test_try_classify_rejects_zero_weight() {
+        let result = TmbCalculator::try_classify(1723.86, 0.0, &Gender::Male);
+        assert_eq!(result, Err(HealthError::Zero { field: "weight" }));
+    }
+}
@@ -0,0 +1,198 @@
+//! Locale-aware parsing of numbers and measurements typed by people.
+//!
+//! Accepts either comma or dot as decimal separator (`70,5` and `70.5`),
+//! optional thousands separators (`1.234,5`, `1,234.5`) and an optional unit
+//! suffix (`70,5kg`, `160 lb`, `175cm`, `5'10"`), converting to the metric
+//! unit each calculator expects.
+
+use crate::error::HealthError;
+use crate::units::{Centimeters, FeetInches, Inches, Kilograms, Meters, Pounds};
+
+/// Parses a number written with comma or dot decimals and optional
+/// thousands separators.
+///
+/// When both separators appear, the last one is the decimal separator. A
+/// single separator is always read as the decimal one, so `1,234` is 1.234:
+/// body measurements never need thousands, and Brazilian users type `70,5`.
+pub fn parse_number(field: &'static str, input: &str) -> Result<f32, HealthError> {
+    let invalid = || HealthError::Unrecognized {
+        field,
+        input: input.trim().to_string(),
+    };
+    let text: String = input.trim().chars().filter(|c| !c.is_whitespace()).collect();
+    if text.is_empty() {
+        return Err(HealthError::Missing { field });
+    }
+
+    let last_separator = text.rfind([',', '.']);
+    let normalized = match last_separator {
+        None => text.clone(),
+        Some(position) => {
+            let separator = text[position..].chars().next().unwrap_or('.');
+            let (integer, fraction) = (&text[..position], &text[position + 1..]);
+            let thousands = if separator == ',' { '.' } else { ',' };
+            if integer.contains(separator) && !integer.contains(thousands) {
+                // Only one kind of separator, repeated: all of them group thousands
+                strip_thousands(&text, separator).ok_or_else(invalid)?
+            } else {
+                let integer = strip_thousands(integer, thousands).ok_or_else(invalid)?;
+                format!("{}.{}", integer, fraction)
+            }
+        }
+    };
+    normalized.parse::<f32>().map_err(|_| invalid())
+}
+
+/// Removes thousands separators, checking that groups have three digits.
+fn strip_thousands(text: &str, separator: char) -> Option<String> {
+    let groups: Vec<&str> = text.split(separator).collect();
+    if groups.len() > 1 {
+        let first = groups[0].trim_start_matches(['-', '+']);
+        if first.is_empty() || first.len() > 3 || groups[1..].iter().any(|g| g.len() != 3) {
+            return None;
+        }
+    }
+    Some(groups.concat())
+}
+
+/// Splits `70,5kg` into (`70,5`, `kg`).
+fn split_suffix(input: &str) -> (&str, String) {
+    let trimmed = input.trim();
+    let end = trimmed
+        .rfind(|c: char| c.is_ascii_digit() || c == ',' || c == '.')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    (&trimmed[..end], trimmed[end..].trim().to_lowercase())
+}
+
+/// Parses a body weight; plain numbers are kilograms, `lb`/`lbs` pounds.
+pub fn parse_weight(input: &str) -> Result<Kilograms, HealthError> {
+    let (number, suffix) = split_suffix(input);
+    let value = parse_number("weight", number)?;
+    match suffix.as_str() {
+        "" | "kg" | "kgs" => Ok(Kilograms(value)),
+        "lb" | "lbs" => Ok(Kilograms::from(Pounds(value))),
+        _ => Err(unrecognized_unit("weight", input)),
+    }
+}
+
+/// Parses a standing height; plain numbers are meters. Also accepts `cm`,
+/// `in` and feet-and-inches such as `5'10"` or `5ft 10in`.
+pub fn parse_height(input: &str) -> Result<Meters, HealthError> {
+    if let Some(feet_inches) = parse_feet_inches(input)? {
+        return Ok(Meters::from(feet_inches));
+    }
+    let (number, suffix) = split_suffix(input);
+    let value = parse_number("height", number)?;
+    match suffix.as_str() {
+        "" | "m" => Ok(Meters(value)),
+        "cm" => Ok(Meters::from(Centimeters(value))),
+        "in" | "\"" => Ok(Meters::from(Inches(value))),
+        _ => Err(unrecognized_unit("height", input)),
+    }
+}
+
+/// Parses a body circumference; plain numbers are centimeters, `in` inches.
+pub fn parse_circumference(field: &'static str, input: &str) -> Result<Centimeters, HealthError> {
+    let (number, suffix) = split_suffix(input);
+    let value = parse_number(field, number)?;
+    match suffix.as_str() {
+        "" | "cm" => Ok(Centimeters(value)),
+        "in" | "\"" => Ok(Centimeters::from(Inches(value))),
+        "m" => Ok(Centimeters::from(Meters(value))),
+        _ => Err(unrecognized_unit(field, input)),
+    }
+}
+
+/// Parses an age in whole years, allowing a trailing `anos`/`years`.
+pub fn parse_age(input: &str) -> Result<u32, HealthError> {
+    let (number, suffix) = split_suffix(input);
+    if number.is_empty() {
+        return Err(HealthError::Missing { field: "age" });
+    }
+    match suffix.as_str() {
+        "" | "anos" | "years" | "y" => number.parse::<u32>().map_err(|_| HealthError::Unrecognized {
+            field: "age",
+            input: input.trim().to_string(),
+        }),
+        _ => Err(unrecognized_unit("age", input)),
+    }
+}
+
+/// Recognizes `5'10"`, `5' 10`, `5ft10in` and `5 ft 10 in`; returns None for other input.
+fn parse_feet_inches(input: &str) -> Result<Option<FeetInches>, HealthError> {
+    let text = input.trim().to_lowercase();
+    let (feet, rest) = match text.split_once('\'').or_else(|| text.split_once("ft")) {
+        Some(parts) => parts,
+        None => return Ok(None),
+    };
+    let invalid = || HealthError::Unrecognized {
+        field: "height",
+        input: input.trim().to_string(),
+    };
+    let feet = feet.trim().parse::<u32>().map_err(|_| invalid())?;
+    let inches = rest.trim().trim_end_matches(['"', '\'']).trim_end_matches("in").trim();
+    let inches = if inches.is_empty() { 0.0 } else { parse_number("height", inches)? };
+    Ok(Some(FeetInches::new(feet, inches)))
+}
+
+fn unrecognized_unit(field: &'static str, input: &str) -> HealthError {
+    HealthError::Unrecognized {
+        field,
+        input: input.trim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comma_and_dot_decimals() {
+        assert_eq!(parse_number("weight", "70,5"), Ok(70.5));
+        assert_eq!(parse_number("weight", " 70.5 "), Ok(70.5));
+        assert_eq!(parse_number("weight", "70"), Ok(70.0));
+    }
+
+    #[test]
+    fn test_thousands_separators() {
+        assert_eq!(parse_number("tmb", "1.723,86"), Ok(1723.86));
+        assert_eq!(parse_number("tmb", "1,723.86"), Ok(1723.86));
+        assert_eq!(parse_number("tmb", "1.234.567"), Ok(1_234_567.0));
+        assert!(parse_number("tmb", "12.34,5").is_err());
+    }
+
+    #[test]
+    fn test_invalid_number() {
+        assert!(matches!(parse_number("weight", "abc"), Err(HealthError::Unrecognized { .. })));
+        assert_eq!(parse_number("weight", "  "), Err(HealthError::Missing { field: "weight" }));
+    }
+
+    #[test]
+    fn test_weight_with_units() {
+        assert_eq!(parse_weight("70,5kg"), Ok(Kilograms(70.5)));
+        assert!((parse_weight("160 lb").unwrap().0 - 72.5748).abs() < 1e-3);
+        assert!(parse_weight("70 stone").is_err());
+    }
+
+    #[test]
+    fn test_height_with_units() {
+        assert_eq!(parse_height("1,75"), Ok(Meters(1.75)));
+        assert!((parse_height("175cm").unwrap().0 - 1.75).abs() < 1e-6);
+        assert!((parse_height("5'10\"").unwrap().0 - 1.778).abs() < 1e-6);
+        assert!((parse_height("5ft 10in").unwrap().0 - 1.778).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_circumference_with_units() {
+        assert_eq!(parse_circumference("waist", "85,5 cm"), Ok(Centimeters(85.5)));
+        assert!((parse_circumference("waist", "34in").unwrap().0 - 86.36).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_age() {
+        assert_eq!(parse_age("30"), Ok(30));
+        assert_eq!(parse_age("30 anos"), Ok(30));
+        assert!(parse_age("30.5").is_err());
+    }
+}
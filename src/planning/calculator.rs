@@ -0,0 +1,203 @@
+//! Daily calorie target and macronutrient grams for a weight goal.
+//!
+//! The target is the TDEE minus (or plus) the energy of the desired weekly
+//! change, at 7700 kcal per kg of body weight. Targets below the basal rate
+//! or below the accepted minimum intake for the person's sex are refused.
+
+use crate::error::{self, HealthError};
+use crate::metabolism::activity::ActivityLevel;
+use crate::metabolism::bmr::BmrFormula;
+use crate::metabolism::tdee::TdeeCalculator;
+use crate::metabolism::tmb::{Gender, TmbData};
+use crate::planning::goal::{DietStyle, Goal};
+
+/// Energy stored in one kilogram of body weight, in kcal.
+pub const KCAL_PER_KG: f32 = 7700.0;
+/// Accepted range for the weekly weight change, in kg/week.
+pub const WEEKLY_RATE_RANGE_KG: (f32, f32) = (0.1, 1.0);
+
+const KCAL_PER_GRAM_PROTEIN: f32 = 4.0;
+const KCAL_PER_GRAM_CARBOHYDRATE: f32 = 4.0;
+const KCAL_PER_GRAM_FAT: f32 = 9.0;
+
+/// What the plan should achieve, and how.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlanSettings {
+    pub activity_level: ActivityLevel,
+    pub goal: Goal,
+    /// Desired weight change in kg/week; zero when maintaining.
+    pub weekly_rate: f32,
+    pub diet: DietStyle,
+    pub formula: BmrFormula,
+}
+
+impl PlanSettings {
+    /// Creates validated settings using the Harris-Benedict TMB.
+    /// The weekly rate is ignored (set to zero) when the goal is to maintain.
+    pub fn new(
+        activity_level: ActivityLevel,
+        goal: Goal,
+        weekly_rate: f32,
+        diet: DietStyle,
+    ) -> Result<Self, HealthError> {
+        let weekly_rate = if goal == Goal::Maintain { 0.0 } else { weekly_rate };
+        let settings = PlanSettings {
+            activity_level,
+            goal,
+            weekly_rate,
+            diet,
+            formula: BmrFormula::HarrisBenedict,
+        };
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Uses another TMB equation.
+    pub fn with_formula(mut self, formula: BmrFormula) -> Self {
+        self.formula = formula;
+        self
+    }
+
+    /// Checks that the weekly rate is plausible for a weight change goal.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        if self.goal != Goal::Maintain {
+            error::validate_measurement("weekly rate", self.weekly_rate, WEEKLY_RATE_RANGE_KG)?;
+        }
+        Ok(())
+    }
+
+    /// Calories added to (positive) or removed from (negative) the TDEE each day.
+    pub fn daily_adjustment(&self) -> f32 {
+        let daily = self.weekly_rate * KCAL_PER_KG / 7.0;
+        match self.goal {
+            Goal::Lose => -daily,
+            Goal::Maintain => 0.0,
+            Goal::Gain => daily,
+        }
+    }
+}
+
+/// Daily grams of each macronutrient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Macros {
+    pub protein_g: f32,
+    pub carbohydrate_g: f32,
+    pub fat_g: f32,
+}
+
+impl Macros {
+    /// Splits `calories` according to the diet style.
+    pub fn for_calories(calories: f32, diet: DietStyle) -> Self {
+        let (protein, carbohydrate, fat) = diet.split();
+        Macros {
+            protein_g: calories * protein / 100.0 / KCAL_PER_GRAM_PROTEIN,
+            carbohydrate_g: calories * carbohydrate / 100.0 / KCAL_PER_GRAM_CARBOHYDRATE,
+            fat_g: calories * fat / 100.0 / KCAL_PER_GRAM_FAT,
+        }
+    }
+}
+
+/// Daily calorie target and macronutrients for a goal.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NutritionPlan {
+    pub settings: PlanSettings,
+    /// Basal metabolic rate, in kcal/day.
+    pub tmb: f32,
+    /// Total daily energy expenditure, in kcal/day.
+    pub tdee: f32,
+    /// Daily calorie target, in kcal/day.
+    pub target_calories: f32,
+    /// Lowest target the plan would accept, in kcal/day.
+    pub minimum_calories: f32,
+    pub macros: Macros,
+}
+
+pub struct PlanCalculator; // Namespace for nutrition planning methods
+
+impl PlanCalculator {
+    /// Minimum accepted daily intake without medical supervision, in kcal/day.
+    pub fn minimum_intake(gender: &Gender) -> f32 {
+        match gender {
+            Gender::Male => 1500.0,
+            Gender::Female => 1200.0,
+        }
+    }
+
+    /// Builds the plan, refusing targets below the TMB or the minimum intake.
+    pub fn plan(data: &TmbData, settings: &PlanSettings) -> Result<NutritionPlan, HealthError> {
+        settings.validate()?;
+        let tdee = TdeeCalculator::assess_with(data, &settings.formula, settings.activity_level)?;
+        let target_calories = error::ensure_finite("calorie target", tdee.tdee + settings.daily_adjustment())?;
+        let minimum_calories = tdee.tmb.value.max(Self::minimum_intake(&data.gender));
+        if target_calories < minimum_calories {
+            return Err(HealthError::UnsafeTarget { target: target_calories, minimum: minimum_calories });
+        }
+        Ok(NutritionPlan {
+            settings: *settings,
+            tmb: tdee.tmb.value,
+            tdee: tdee.tdee,
+            target_calories,
+            minimum_calories,
+            macros: Macros::for_calories(target_calories, settings.diet),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn male() -> TmbData {
+        TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap()
+    }
+
+    #[test]
+    fn test_lose_weight_plan() {
+        let settings = PlanSettings::new(ActivityLevel::ModeratelyActive, Goal::Lose, 0.5, DietStyle::Balanced).unwrap();
+        let plan = PlanCalculator::plan(&male(), &settings).unwrap();
+        // 2671.98 − 0.5 × 7700 / 7 = 2121.98
+        assert!((plan.target_calories - 2121.98).abs() < 0.1);
+        assert!((plan.macros.protein_g - 106.1).abs() < 0.1);
+        assert!((plan.macros.carbohydrate_g - 265.25).abs() < 0.1);
+        assert!((plan.macros.fat_g - 70.73).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_maintain_ignores_rate() {
+        let settings = PlanSettings::new(ActivityLevel::Sedentary, Goal::Maintain, 5.0, DietStyle::Ketogenic).unwrap();
+        assert_eq!(settings.weekly_rate, 0.0);
+        let plan = PlanCalculator::plan(&male(), &settings).unwrap();
+        assert_eq!(plan.target_calories, plan.tdee);
+    }
+
+    #[test]
+    fn test_gain_adds_surplus() {
+        let settings = PlanSettings::new(ActivityLevel::Sedentary, Goal::Gain, 0.25, DietStyle::HighProtein).unwrap();
+        let plan = PlanCalculator::plan(&male(), &settings).unwrap();
+        assert!((plan.target_calories - plan.tdee - 275.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_refuses_target_below_tmb() {
+        let settings = PlanSettings::new(ActivityLevel::ModeratelyActive, Goal::Lose, 1.0, DietStyle::Balanced).unwrap();
+        let result = PlanCalculator::plan(&male(), &settings);
+        assert!(matches!(result, Err(HealthError::UnsafeTarget { minimum, .. }) if (minimum - 1723.86).abs() < 0.1));
+    }
+
+    #[test]
+    fn test_refuses_target_below_minimum_intake() {
+        let data = TmbData::new(45.0, 1.55, 60, Gender::Female).unwrap();
+        let settings = PlanSettings::new(ActivityLevel::LightlyActive, Goal::Lose, 0.5, DietStyle::Balanced).unwrap();
+        let result = PlanCalculator::plan(&data, &settings);
+        assert!(matches!(result, Err(HealthError::UnsafeTarget { minimum, .. }) if minimum == 1200.0));
+    }
+
+    #[test]
+    fn test_rejects_implausible_rate() {
+        let result = PlanSettings::new(ActivityLevel::Sedentary, Goal::Lose, 2.0, DietStyle::Balanced);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "weekly rate", .. })));
+    }
+}
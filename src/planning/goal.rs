@@ -0,0 +1,94 @@
+//! What a person wants to achieve and how they like to eat.
+
+use std::str::FromStr;
+
+use crate::error::HealthError;
+
+/// Direction of the desired weight change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Goal {
+    Lose,
+    Maintain,
+    Gain,
+}
+
+impl FromStr for Goal {
+    type Err = HealthError;
+
+    /// Parses `lose`, `maintain` and `gain`, in English or Portuguese.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "lose" | "loss" | "perder" | "emagrecer" => Ok(Goal::Lose),
+            "maintain" | "maintenance" | "manter" | "manutenção" | "manutencao" => Ok(Goal::Maintain),
+            "gain" | "ganhar" => Ok(Goal::Gain),
+            _ => Err(HealthError::Unrecognized {
+                field: "goal",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Share of the daily calories given to each macronutrient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DietStyle {
+    Balanced,
+    LowCarb,
+    HighProtein,
+    Ketogenic,
+}
+
+impl DietStyle {
+    /// Percentages of calories from protein, carbohydrate and fat (sum to 100).
+    pub fn split(&self) -> (f32, f32, f32) {
+        match self {
+            DietStyle::Balanced => (20.0, 50.0, 30.0),
+            DietStyle::LowCarb => (30.0, 20.0, 50.0),
+            DietStyle::HighProtein => (35.0, 40.0, 25.0),
+            DietStyle::Ketogenic => (20.0, 5.0, 75.0),
+        }
+    }
+}
+
+impl FromStr for DietStyle {
+    type Err = HealthError;
+
+    /// Parses `balanced`, `low-carb`, `high-protein` and `keto`, in English or Portuguese.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "balanced" | "equilibrada" => Ok(DietStyle::Balanced),
+            "lowcarb" => Ok(DietStyle::LowCarb),
+            "highprotein" | "hiperproteica" => Ok(DietStyle::HighProtein),
+            "keto" | "ketogenic" | "cetogenica" | "cetogênica" => Ok(DietStyle::Ketogenic),
+            _ => Err(HealthError::Unrecognized {
+                field: "diet",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splits_sum_to_100() {
+        for diet in [DietStyle::Balanced, DietStyle::LowCarb, DietStyle::HighProtein, DietStyle::Ketogenic] {
+            let (protein, carbohydrate, fat) = diet.split();
+            assert_eq!(protein + carbohydrate + fat, 100.0);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("Lose".parse::<Goal>(), Ok(Goal::Lose));
+        assert_eq!("manter".parse::<Goal>(), Ok(Goal::Maintain));
+        assert_eq!("low-carb".parse::<DietStyle>(), Ok(DietStyle::LowCarb));
+        assert_eq!("keto".parse::<DietStyle>(), Ok(DietStyle::Ketogenic));
+        assert!("paleo".parse::<DietStyle>().is_err());
+    }
+}
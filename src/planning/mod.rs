@@ -0,0 +1,3 @@
+pub mod goal; // Weight goal and diet style
+pub mod calculator; // Calorie target and macronutrient plan
+pub mod projection; // Day-by-day weight projection
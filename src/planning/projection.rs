@@ -0,0 +1,213 @@
+//! Day-by-day projection of body weight under a fixed calorie intake.
+//!
+//! Each simulated day recomputes the TMB (and so the TDEE) for the current
+//! weight, so the projected loss slows down as the person gets lighter
+//! instead of following a constant "500 kcal = 0.5 kg/week" rule.
+
+use crate::bmi::calculator::{BmiCalculator, BmiCategory, BmiData};
+use crate::body_fat::{self, BodyFatCalculator};
+use crate::common::date::Date;
+use crate::error::{self, HealthError};
+use crate::metabolism::activity::ActivityLevel;
+use crate::metabolism::bmr::BmrFormula;
+use crate::metabolism::tmb::{TmbCalculator, TmbData};
+use crate::planning::calculator::KCAL_PER_KG;
+
+/// Accepted range for the daily calorie intake, in kcal/day.
+pub const DAILY_INTAKE_RANGE_KCAL: (f32, f32) = (500.0, 10000.0);
+/// Longest projection, in days (ten years).
+pub const MAX_PROJECTION_DAYS: u32 = 3650;
+
+/// Conditions of the simulation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectionSettings {
+    /// Calories eaten every day, in kcal/day.
+    pub daily_intake: f32,
+    pub activity_level: ActivityLevel,
+    /// Number of days to simulate.
+    pub days: u32,
+    /// Date of day zero.
+    pub start: Date,
+    /// BMI category whose first day should be reported.
+    pub target: Option<BmiCategory>,
+    pub formula: BmrFormula,
+}
+
+impl ProjectionSettings {
+    /// Creates validated settings using the Harris-Benedict TMB and no target.
+    pub fn new(daily_intake: f32, activity_level: ActivityLevel, days: u32, start: Date) -> Result<Self, HealthError> {
+        let settings = ProjectionSettings {
+            daily_intake,
+            activity_level,
+            days,
+            start,
+            target: None,
+            formula: BmrFormula::HarrisBenedict,
+        };
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Reports the first day on which the BMI falls into `target`.
+    pub fn with_target(mut self, target: BmiCategory) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Uses another TMB equation.
+    pub fn with_formula(mut self, formula: BmrFormula) -> Self {
+        self.formula = formula;
+        self
+    }
+
+    /// Checks the intake and the length of the projection.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("daily intake", self.daily_intake, DAILY_INTAKE_RANGE_KCAL)?;
+        if self.days == 0 || self.days > MAX_PROJECTION_DAYS {
+            return Err(HealthError::Implausible {
+                field: "days",
+                value: self.days as f32,
+                min: 1.0,
+                max: MAX_PROJECTION_DAYS as f32,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Projected state at the end of one day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectionPoint {
+    /// Days since the start (0 is the starting state).
+    pub day: u32,
+    pub date: Date,
+    /// Weight in kg.
+    pub weight: f32,
+    pub bmi: f32,
+    /// Body fat percentage (Deurenberg).
+    pub pgc: f32,
+}
+
+/// Projected trajectory and, if requested, when the target category is reached.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Projection {
+    pub settings: ProjectionSettings,
+    /// One point per day, starting with day 0.
+    pub points: Vec<ProjectionPoint>,
+    /// First point whose BMI falls into the target category.
+    pub target_reached: Option<ProjectionPoint>,
+}
+
+pub struct ProjectionCalculator; // Namespace for weight projection methods
+
+impl ProjectionCalculator {
+    /// Simulates the weight day by day, recomputing the TMB for each new weight.
+    ///
+    /// The simulation stops early if the weight or the body fat percentage
+    /// leaves the plausible range.
+    pub fn project(data: &TmbData, settings: &ProjectionSettings) -> Result<Projection, HealthError> {
+        data.validate()?;
+        settings.validate()?;
+        settings.formula.validate(data.weight)?;
+
+        let mut current = TmbData { weight: data.weight, ..*data };
+        let mut points = Vec::with_capacity(settings.days as usize + 1);
+        points.push(Self::point(&current, 0, settings.start)?);
+        for day in 1..=settings.days {
+            let tdee = TmbCalculator::calculate_with(&current, &settings.formula) * settings.activity_level.multiplier();
+            let weight = current.weight + (settings.daily_intake - tdee) / KCAL_PER_KG;
+            if error::validate_measurement("weight", weight, error::WEIGHT_RANGE_KG).is_err() {
+                break;
+            }
+            current.weight = weight;
+            match Self::point(&current, day, settings.start) {
+                Err(HealthError::InvalidResult { .. }) => break,
+                point => points.push(point?),
+            }
+        }
+
+        let target_reached = settings
+            .target
+            .and_then(|target| points.iter().find(|point| BmiCalculator::classify(point.bmi) == target).copied());
+        Ok(Projection { settings: *settings, points, target_reached })
+    }
+
+    fn point(data: &TmbData, day: u32, start: Date) -> Result<ProjectionPoint, HealthError> {
+        let bmi = BmiCalculator::try_calculate(&BmiData { weight: data.weight, height: data.height })?;
+        let pgc = body_fat::plausible_pgc(BodyFatCalculator::calculate_pgc(bmi, data.age, &data.gender))?;
+        Ok(ProjectionPoint {
+            day,
+            date: start.add_days(day as i64),
+            weight: data.weight,
+            bmi,
+            pgc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metabolism::tmb::Gender;
+
+    fn start() -> Date {
+        Date::new(2025, 1, 1).unwrap()
+    }
+
+    #[test]
+    fn test_loss_slows_down_as_weight_drops() {
+        let data = TmbData::new(100.0, 1.75, 40, Gender::Male).unwrap();
+        let settings = ProjectionSettings::new(2000.0, ActivityLevel::Sedentary, 180, start()).unwrap();
+        let projection = ProjectionCalculator::project(&data, &settings).unwrap();
+        assert_eq!(projection.points.len(), 181);
+        let first_month = projection.points[0].weight - projection.points[30].weight;
+        let last_month = projection.points[150].weight - projection.points[180].weight;
+        assert!(first_month > last_month && last_month > 0.0);
+        assert_eq!(projection.points[180].date, Date::new(2025, 6, 30).unwrap());
+    }
+
+    #[test]
+    fn test_maintenance_intake_keeps_weight() {
+        let data = TmbData::new(70.0, 1.75, 25, Gender::Male).unwrap();
+        let tdee = TmbCalculator::calculate(&data) * ActivityLevel::Sedentary.multiplier();
+        let settings = ProjectionSettings::new(tdee, ActivityLevel::Sedentary, 30, start()).unwrap();
+        let projection = ProjectionCalculator::project(&data, &settings).unwrap();
+        assert!((projection.points[30].weight - 70.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_target_category_date() {
+        let data = TmbData::new(80.0, 1.75, 40, Gender::Male).unwrap();
+        let settings = ProjectionSettings::new(1800.0, ActivityLevel::LightlyActive, 365, start())
+            .unwrap()
+            .with_target(BmiCategory::NormalWeight);
+        let projection = ProjectionCalculator::project(&data, &settings).unwrap();
+        let reached = projection.target_reached.unwrap();
+        assert!(reached.bmi < 25.0);
+        assert!(projection.points[reached.day as usize - 1].bmi >= 25.0);
+    }
+
+    #[test]
+    fn test_stops_when_body_fat_leaves_plausible_range() {
+        let data = TmbData::new(45.0, 1.80, 20, Gender::Male).unwrap();
+        let settings = ProjectionSettings::new(500.0, ActivityLevel::VeryActive, 365, start()).unwrap();
+        let projection = ProjectionCalculator::project(&data, &settings).unwrap();
+        assert!(projection.points.len() < 366);
+        assert!(projection.points.iter().all(|point| point.pgc >= 0.0));
+    }
+
+    #[test]
+    fn test_rejects_invalid_settings() {
+        assert!(matches!(
+            ProjectionSettings::new(100.0, ActivityLevel::Sedentary, 30, start()),
+            Err(HealthError::Implausible { field: "daily intake", .. })
+        ));
+        assert!(matches!(
+            ProjectionSettings::new(2000.0, ActivityLevel::Sedentary, 0, start()),
+            Err(HealthError::Implausible { field: "days", .. })
+        ));
+    }
+}
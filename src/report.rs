@@ -0,0 +1,578 @@
+//! Rendering layer: turns typed assessments into human-readable text.
+//!
+//! Calculators return [`Assessment`] values; only this module decides how
+//! they are worded and formatted, in any language of the [`i18n`] catalogs.
+
+use crate::assessment::{Assessment, Classification, Formula, ReferenceRange, RiskLevel, Unit};
+use crate::bmi::calculator::{BmiCategory, BmiForAge};
+use crate::bmi::ideal_weight::WeightTargets;
+use crate::bmi::index::{BmiIndexAssessment, PonderalAssessment};
+use crate::bmi::pediatric::PediatricBmiResult;
+use crate::bsa::calculator::BsaResult;
+use crate::body_fat::comparison::BodyFatComparison;
+use crate::body_fat::composition::BodyComposition;
+use crate::body_fat::BodyFatClassification;
+use crate::i18n::{self, Lang, Message};
+use crate::metabolism::tdee::TdeeResult;
+use crate::metabolism::tmb::TmbCategory;
+use crate::planning::calculator::NutritionPlan;
+use crate::planning::goal::Goal;
+use crate::planning::projection::Projection;
+use crate::whr::calculator::WhrGradedAssessment;
+use crate::whr::shape::BodyShape;
+use crate::whr::waist::{WaistAssessment, WhtrAssessment};
+
+/// Something that can be presented to the user as text.
+pub trait Render {
+    /// Text in the given language.
+    fn render_in(&self, lang: Lang) -> String;
+
+    /// Text in English.
+    fn render(&self) -> String {
+        self.render_in(Lang::EnUs)
+    }
+}
+
+impl Render for Assessment<BmiCategory> {
+    fn render_in(&self, lang: Lang) -> String {
+        let title = match self.formula {
+            Formula::BmiPrime => Message::BmiPrimeTitle,
+            Formula::Trefethen => Message::NewBmiTitle,
+            _ => Message::BmiTitle,
+        };
+        render_assessment(lang, title, self, self.category.label_in(lang))
+    }
+}
+
+impl Render for PonderalAssessment {
+    fn render_in(&self, lang: Lang) -> String {
+        render_assessment(lang, Message::PonderalTitle, self, self.category.label_in(lang))
+    }
+}
+
+impl Render for BmiIndexAssessment {
+    fn render_in(&self, lang: Lang) -> String {
+        match self {
+            BmiIndexAssessment::Bmi(assessment) => assessment.render_in(lang),
+            BmiIndexAssessment::Ponderal(assessment) => assessment.render_in(lang),
+        }
+    }
+}
+
+impl Render for PediatricBmiResult {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        let mut lines = vec![
+            format!("{}: {}", label(Message::BmiTitle), format_value(lang, self.bmi, Unit::KilogramsPerSquareMeter)),
+            format!(
+                "{}: {}",
+                label(Message::ZScoreLabel),
+                i18n::format(
+                    lang,
+                    Message::ZScoreWithPercentile,
+                    &[&lang.decimal(self.z_score, 2), &lang.decimal(self.percentile, 1)]
+                )
+            ),
+            format!("{}: {}", label(Message::ClassificationLabel), self.category.label_in(lang)),
+            format!("{}: {}", label(Message::GrowthReferenceLabel), label(Message::GrowthReference(self.reference))),
+        ];
+        if let Some(risk) = self.risk {
+            lines.push(format!("{}: {}", label(Message::RiskLabel), risk.label_in(lang)));
+        }
+        lines.join("\n")
+    }
+}
+
+impl Render for BmiForAge {
+    fn render_in(&self, lang: Lang) -> String {
+        match self {
+            BmiForAge::Adult(assessment) => assessment.render_in(lang),
+            BmiForAge::Pediatric(result) => result.render_in(lang),
+        }
+    }
+}
+
+impl Render for WeightTargets {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        let kg = |value: f32| format_value(lang, value, Unit::Kilograms);
+        let range = &self.healthy_range;
+        let mut lines = vec![format!(
+            "{}: {} - {}",
+            label(Message::HealthyWeightRangeLabel),
+            lang.decimal(range.min, 2),
+            kg(range.max)
+        )];
+        if !self.ideal.is_empty() {
+            let by_formula: Vec<String> = self
+                .ideal
+                .iter()
+                .map(|ideal| format!("{} {}", label(Message::Formula(ideal.formula)), kg(ideal.weight)))
+                .collect();
+            lines.push(format!("{}: {}", label(Message::IdealWeightLabel), by_formula.join(", ")));
+        }
+        if let Some(adjusted) = self.adjusted {
+            lines.push(format!("{}: {}", label(Message::AdjustedWeightLabel), kg(adjusted)));
+        }
+        lines.join("\n")
+    }
+}
+
+impl Render for Assessment<TmbCategory> {
+    fn render_in(&self, lang: Lang) -> String {
+        render_assessment(lang, Message::TmbTitle, self, self.category.label_in(lang))
+    }
+}
+
+impl Render for Assessment<BodyFatClassification> {
+    fn render_in(&self, lang: Lang) -> String {
+        let by_sex = self.category.by_sex.label_in(lang);
+        let classification = match self.category.by_age {
+            Some(by_age) => i18n::format(lang, Message::BodyFatClassification, &[&by_sex, &by_age.label_in(lang)]),
+            None => i18n::format(lang, Message::BodyFatClassificationBySex, &[&by_sex]),
+        };
+        render_assessment(lang, Message::PgcTitle, self, &classification)
+    }
+}
+
+impl Render for BodyFatComparison {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        let percent = |value: f32| format_value(lang, value, Unit::Percent);
+        let mut lines = vec![format!("{}:", label(Message::PgcComparisonTitle))];
+        for estimate in &self.estimates {
+            lines.push(format!(
+                "{}: {} ({})",
+                label(Message::Formula(estimate.formula)),
+                percent(estimate.value),
+                estimate.category.by_sex.label_in(lang)
+            ));
+        }
+        if !self.skipped.is_empty() {
+            let skipped: Vec<&str> = self.skipped.iter().map(|formula| label(Message::Formula(*formula))).collect();
+            lines.push(format!("{}: {}", label(Message::SkippedLabel), skipped.join(", ")));
+        }
+        lines.push(format!(
+            "{}: {}",
+            label(Message::SpreadLabel),
+            i18n::format(lang, Message::PgcSpread, &[&lang.decimal(self.min, 2), &percent(self.max), &lang.decimal(self.spread, 2)])
+        ));
+        lines.push(format!("{}: {}", label(Message::MeanLabel), percent(self.mean)));
+        lines.join("\n")
+    }
+}
+
+impl Render for BodyComposition {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        let kg = |value: f32| format_value(lang, value, Unit::Kilograms);
+        let bmi_unit = |value: f32| format_value(lang, value, Unit::KilogramsPerSquareMeter);
+        let lean_body_mass: Vec<String> = self
+            .lean_body_mass
+            .iter()
+            .map(|estimate| format!("{} {}", label(Message::Formula(estimate.formula)), kg(estimate.lean_mass)))
+            .collect();
+        let mut lines = vec![
+            format!(
+                "{}: {} ({}, {})",
+                label(Message::FatMassLabel),
+                kg(self.fat_mass),
+                format_value(lang, self.pgc, Unit::Percent),
+                label(Message::Formula(self.pgc_formula))
+            ),
+            format!("{}: {}", label(Message::FatFreeMassLabel), kg(self.fat_free_mass)),
+        ];
+        if !lean_body_mass.is_empty() {
+            lines.push(format!("{}: {}", label(Message::LeanBodyMassLabel), lean_body_mass.join(", ")));
+        }
+        lines.extend([
+            format!("{}: {}", label(Message::FfmiLabel), bmi_unit(self.ffmi)),
+            format!(
+                "{}: {} ({})",
+                label(Message::NormalizedFfmiLabel),
+                bmi_unit(self.normalized_ffmi),
+                self.ffmi_category.label_in(lang)
+            ),
+        ]);
+        lines.join("\n")
+    }
+}
+
+impl Render for Assessment<RiskLevel> {
+    fn render_in(&self, lang: Lang) -> String {
+        let classification = i18n::format(lang, Message::WhrClassification, &[&self.category.label_in(lang)]);
+        render_assessment(lang, Message::WhrTitle, self, &classification)
+    }
+}
+
+impl Render for WhrGradedAssessment {
+    fn render_in(&self, lang: Lang) -> String {
+        let classification = i18n::format(lang, Message::WhrClassification, &[&self.category.label_in(lang)]);
+        render_assessment(lang, Message::WhrTitle, self, &classification)
+    }
+}
+
+impl Render for WhtrAssessment {
+    fn render_in(&self, lang: Lang) -> String {
+        render_assessment(lang, Message::WhtrTitle, self, self.category.label_in(lang))
+    }
+}
+
+impl Render for WaistAssessment {
+    fn render_in(&self, lang: Lang) -> String {
+        render_assessment(lang, Message::WaistTitle, self, self.category.label_in(lang))
+    }
+}
+
+impl Render for BodyShape {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        let absi = i18n::format(
+            lang,
+            Message::AbsiWithZScore,
+            &[&lang.decimal(self.absi, 4), &lang.decimal(self.absi_z, 2), &self.absi_risk.label_in(lang)],
+        );
+        [
+            format!("{}: {}", label(Message::AbsiLabel), absi),
+            format!("{}: {}", label(Message::BriLabel), lang.decimal(self.bri, 2)),
+            format!("{}: {}", label(Message::ConicityLabel), lang.decimal(self.conicity_index, 2)),
+        ]
+        .join("\n")
+    }
+}
+
+impl Render for BsaResult {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        let mut lines = vec![label(Message::BsaTitle).to_string()];
+        lines.extend(self.estimates.iter().map(|estimate| {
+            format!(
+                "{}: {}",
+                label(Message::Formula(estimate.formula)),
+                format_value(lang, estimate.bsa, Unit::SquareMeters)
+            )
+        }));
+        lines.join("\n")
+    }
+}
+
+impl Render for TdeeResult {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        format!(
+            "{}\n{}: {} (× {})\n{}: {}",
+            self.tmb.render_in(lang),
+            label(Message::ActivityLabel),
+            label(self.activity_level.message()),
+            lang.decimal(self.multiplier, 3),
+            label(Message::TdeeTitle),
+            format_value(lang, self.tdee, Unit::KilocaloriesPerDay),
+        )
+    }
+}
+
+impl Render for NutritionPlan {
+    fn render_in(&self, lang: Lang) -> String {
+        let label = |message: Message| i18n::text(lang, message);
+        let kcal = |value: f32| format_value(lang, value, Unit::KilocaloriesPerDay);
+        let goal = match self.settings.goal {
+            Goal::Maintain => label(Message::Goal(Goal::Maintain)).to_string(),
+            goal => i18n::format(
+                lang,
+                Message::GoalWithRate,
+                &[&label(Message::Goal(goal)), &lang.decimal(self.settings.weekly_rate, 2)],
+            ),
+        };
+        let (protein, carbohydrate, fat) = self.settings.diet.split();
+        let grams = |message: Message, value: f32, percent: f32| {
+            format!("{}: {} g ({}%)", label(message), lang.decimal(value, 0), lang.decimal(percent, 0))
+        };
+        [
+            format!("{}: {}", label(Message::TmbTitle), kcal(self.tmb)),
+            format!("{}: {}", label(Message::TdeeTitle), kcal(self.tdee)),
+            format!("{}: {}", label(Message::GoalLabel), goal),
+            format!("{}: {}", label(Message::CalorieTargetLabel), kcal(self.target_calories)),
+            format!("{}: {}", label(Message::DietLabel), label(Message::Diet(self.settings.diet))),
+            grams(Message::ProteinLabel, self.macros.protein_g, protein),
+            grams(Message::CarbohydrateLabel, self.macros.carbohydrate_g, carbohydrate),
+            grams(Message::FatLabel, self.macros.fat_g, fat),
+        ]
+        .join("\n")
+    }
+}
+
+impl Render for Projection {
+    /// Weekly rows of the trajectory (plus the last day), then the target outcome.
+    fn render_in(&self, lang: Lang) -> String {
+        let mut lines = vec![i18n::text(lang, Message::ProjectionHeader).to_string()];
+        let last = self.points.len().saturating_sub(1);
+        for (index, point) in self.points.iter().enumerate() {
+            if index % 7 == 0 || index == last {
+                lines.push(format!(
+                    "{} | {} | {} | {} | {}",
+                    point.day,
+                    point.date,
+                    lang.decimal(point.weight, 1),
+                    lang.decimal(point.bmi, 1),
+                    lang.decimal(point.pgc, 1)
+                ));
+            }
+        }
+        if let Some(target) = self.settings.target {
+            let category = target.label_in(lang);
+            lines.push(match &self.target_reached {
+                Some(point) => i18n::format(lang, Message::TargetReached, &[&category, &point.date, &point.day]),
+                None => i18n::format(lang, Message::TargetNotReached, &[&category, &last]),
+            });
+        }
+        lines.join("\n")
+    }
+}
+
+/// Formats a value with two decimals followed by its unit symbol.
+pub fn format_value(lang: Lang, value: f32, unit: Unit) -> String {
+    let number = lang.decimal(value, 2);
+    match unit {
+        Unit::Ratio => number,
+        Unit::Percent => format!("{}%", number),
+        _ => format!("{} {}", number, i18n::text(lang, Message::Unit(unit))),
+    }
+}
+
+/// Formats a reference range such as `18.50 - 25.00 kg/m²` or `< 0.90`.
+pub fn format_range(lang: Lang, range: &ReferenceRange, unit: Unit) -> String {
+    match (range.min, range.max) {
+        (Some(min), Some(max)) => format!("{} - {}", lang.decimal(min, 2), format_value(lang, max, unit)),
+        (Some(min), None) => format!(">= {}", format_value(lang, min, unit)),
+        (None, Some(max)) if range.max_inclusive => format!("<= {}", format_value(lang, max, unit)),
+        (None, Some(max)) => format!("< {}", format_value(lang, max, unit)),
+        (None, None) => "-".to_string(),
+    }
+}
+
+fn render_assessment<C>(lang: Lang, title: Message, assessment: &Assessment<C>, classification: &str) -> String {
+    let label = |message: Message| i18n::text(lang, message);
+    let mut lines = vec![
+        format!("{}: {}", label(title), format_value(lang, assessment.value, assessment.unit)),
+        format!("{}: {}", label(Message::ClassificationLabel), classification),
+        format!(
+            "{}: {}",
+            label(Message::ReferenceRangeLabel),
+            format_range(lang, &assessment.reference_range, assessment.unit)
+        ),
+        format!("{}: {}", label(Message::FormulaLabel), label(Message::Formula(assessment.formula))),
+    ];
+    if let Some(risk) = assessment.risk {
+        lines.push(format!("{}: {}", label(Message::RiskLabel), risk.label_in(lang)));
+    }
+    if let Some(standard) = assessment.standard {
+        lines.push(format!("{}: {}", label(Message::StandardLabel), label(Message::Standard(standard))));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bmi::calculator::{BmiCalculator, BmiData};
+    use crate::common::sex::Sex;
+    use crate::whr::calculator::{WhrCalculator, WhrCalculatorTrait, WhrData};
+
+    #[test]
+    fn test_render_bmi() {
+        let assessment = BmiCalculator::assess(&BmiData::new(70.0, 1.75).unwrap()).unwrap();
+        let expected = "BMI: 22.86 kg/m²\n\
+                        Classification: Normal weight\n\
+                        Reference range: 18.50 - 25.00 kg/m²\n\
+                        Formula: Quetelet\n\
+                        Risk: Low";
+        assert_eq!(assessment.render(), expected);
+    }
+
+    #[test]
+    fn test_render_bmi_with_standard() {
+        use crate::bmi::standard::BmiReferenceStandard;
+        let data = BmiData::new(70.0, 1.70).unwrap();
+        let rendered = BmiCalculator::assess_with_standard(&data, BmiReferenceStandard::WhoAsianPacific).unwrap().render();
+        assert!(rendered.contains("Classification: Overweight"));
+        assert!(rendered.contains("Reference range: 18.50 - 23.00 kg/m²"));
+        assert!(rendered.ends_with("Standard: WHO Asian-Pacific"));
+    }
+
+    #[test]
+    fn test_render_ponderal_index() {
+        use crate::bmi::index::{BmiIndex, BmiIndexCalculator};
+        let data = BmiData::new(70.0, 1.75).unwrap();
+        let expected = "Ponderal Index: 13.06 kg/m³\n\
+                        Classification: Normal\n\
+                        Reference range: 11.00 - 15.00 kg/m³\n\
+                        Formula: Rohrer (Ponderal Index)\n\
+                        Risk: Low";
+        assert_eq!(BmiIndexCalculator::assess(&data, BmiIndex::Ponderal).unwrap().render(), expected);
+        let prime = BmiIndexCalculator::assess(&data, BmiIndex::Prime).unwrap().render_in(Lang::PtBr);
+        assert!(prime.starts_with("IMC Prime: 0,91\nClassificação: Peso normal\nFaixa de referência: 0,74 - 1,00"));
+    }
+
+    #[test]
+    fn test_render_weight_targets() {
+        use crate::bmi::ideal_weight::IdealWeightCalculator;
+        use crate::bmi::standard::BmiReferenceStandard;
+        let targets =
+            IdealWeightCalculator::targets(110.0, 1.75, Some(Sex::Male), BmiReferenceStandard::WhoInternational).unwrap();
+        let expected = "Healthy weight range: 56.66 - 76.56 kg\n\
+                        Ideal body weight: Devine 70.46 kg, Robinson 68.91 kg, Miller 68.75 kg, Hamwi 72.02 kg\n\
+                        Adjusted body weight: 86.28 kg";
+        assert_eq!(targets.render(), expected);
+    }
+
+    #[test]
+    fn test_render_body_fat_comparison() {
+        use crate::body_fat::comparison::{BodyFatComparator, BodyFatProfile};
+        let profile = BodyFatProfile::new(70.0, 1.75, 30, Sex::Male).with_waist(85.0);
+        let rendered = BodyFatComparator::compare(&profile).unwrap().render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "Body Fat Percentage (PGC) by method:");
+        assert_eq!(lines[1], "Deurenberg: 18.13% (Acceptable)");
+        assert!(lines[2].starts_with("CUN-BAE: "));
+        assert_eq!(lines[3], "Relative Fat Mass (RFM): 22.82% (Acceptable)");
+        assert!(lines[4].starts_with("Spread: ") && lines[4].ends_with("percentage points)"));
+        assert!(lines[5].starts_with("Mean: "));
+    }
+
+    #[test]
+    fn test_render_body_composition() {
+        use crate::assessment::Formula;
+        use crate::body_fat::composition::{BodyCompositionCalculator, BodyCompositionData};
+        let data = BodyCompositionData::new(70.0, 1.75, Sex::Male).unwrap();
+        let rendered = BodyCompositionCalculator::calculate(&data, 20.0, Formula::UsNavy).unwrap().render();
+        let expected = "Fat mass: 14.00 kg (20.00%, US Navy)\n\
+                        Fat-free mass: 56.00 kg\n\
+                        Lean body mass: Boer 56.01 kg, James 56.52 kg, Hume 52.81 kg\n\
+                        Fat-Free Mass Index (FFMI): 18.29 kg/m²\n\
+                        Normalized FFMI (1.80 m): 18.59 kg/m² (Average)";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_render_whr() {
+        let data = WhrData::new(95.0, 100.0, Sex::Male).unwrap();
+        let rendered = WhrCalculator::assess(&data).unwrap().render();
+        assert!(rendered.starts_with("Waist-to-Hip Ratio (WHR): 0.95"));
+        assert!(rendered.contains("Reference range: <= 0.90"));
+        assert!(rendered.contains("Risk: High"));
+    }
+
+    #[test]
+    fn test_render_whr_graded_by_age() {
+        let data = WhrData::new(85.0, 100.0, Sex::Male).unwrap();
+        let rendered = WhrCalculator::assess_for_age(&data, 25).unwrap().render();
+        assert!(rendered.contains("Classification: Moderate cardiovascular risk"));
+        assert!(rendered.contains("Reference range: < 0.83"));
+    }
+
+    #[test]
+    fn test_render_waist_with_standard() {
+        use crate::whr::waist::{IdfPopulation, WaistCalculator, WaistData, WaistStandard};
+        let data = WaistData::new(92.0, Sex::Male).unwrap();
+        let standard = WaistStandard::Idf(IdfPopulation::SouthAsian);
+        let expected = "Waist circumference: 92.00 cm\n\
+                        Classification: Increased risk of metabolic complications\n\
+                        Reference range: < 90.00 cm\n\
+                        Formula: Waist circumference\n\
+                        Risk: High\n\
+                        Standard: IDF (South Asian)";
+        assert_eq!(WaistCalculator::assess_with_standard(&data, standard).unwrap().render(), expected);
+    }
+
+    #[test]
+    fn test_render_body_shape_in_portuguese() {
+        use crate::whr::shape::{BodyShapeCalculator, BodyShapeData};
+        let data = BodyShapeData::new(70.0, 1.75, 85.0, 40, Sex::Male).unwrap();
+        let expected = "Índice de Forma Corporal (ABSI): 0,0798 (escore z -0,42: Risco de mortalidade baixo)\n\
+                        Índice de Arredondamento Corporal (BRI): 3,09\n\
+                        Índice de Conicidade: 1,23";
+        assert_eq!(BodyShapeCalculator::calculate(&data).unwrap().render_in(Lang::PtBr), expected);
+    }
+
+    #[test]
+    fn test_render_bsa() {
+        use crate::bsa::calculator::{BsaCalculator, BsaFormula};
+        let data = BmiData::new(70.0, 1.75).unwrap();
+        let result = BsaCalculator::estimate(&data, &[BsaFormula::Mosteller, BsaFormula::DuBois]).unwrap();
+        assert_eq!(result.render(), "Body surface area (BSA)\nMosteller: 1.84 m²\nDuBois: 1.85 m²");
+    }
+
+    #[test]
+    fn test_render_bmi_in_portuguese() {
+        let assessment = BmiCalculator::assess(&BmiData::new(70.0, 1.75).unwrap()).unwrap();
+        let expected = "IMC: 22,86 kg/m²\n\
+                        Classificação: Peso normal\n\
+                        Faixa de referência: 18,50 - 25,00 kg/m²\n\
+                        Fórmula: Quetelet\n\
+                        Risco: Baixo";
+        assert_eq!(assessment.render_in(Lang::PtBr), expected);
+    }
+
+    #[test]
+    fn test_render_tdee() {
+        use crate::metabolism::activity::ActivityLevel;
+        use crate::metabolism::tdee::TdeeCalculator;
+        use crate::metabolism::tmb::TmbData;
+        let data = TmbData::new(70.0, 1.75, 25, Sex::Male).unwrap();
+        let rendered = TdeeCalculator::assess(&data, ActivityLevel::ModeratelyActive).unwrap().render();
+        assert!(rendered.starts_with("Basal Metabolic Rate (TMB): 1723.86 kcal/day"));
+        assert!(rendered.contains("Activity level: Moderately active (× 1.550)"));
+        assert!(rendered.ends_with("Total Daily Energy Expenditure (TDEE): 2671.98 kcal/day"));
+    }
+
+    #[test]
+    fn test_render_plan_in_portuguese() {
+        use crate::metabolism::activity::ActivityLevel;
+        use crate::metabolism::tmb::TmbData;
+        use crate::planning::calculator::{PlanCalculator, PlanSettings};
+        use crate::planning::goal::DietStyle;
+        let data = TmbData::new(70.0, 1.75, 25, Sex::Male).unwrap();
+        let settings = PlanSettings::new(ActivityLevel::ModeratelyActive, Goal::Lose, 0.5, DietStyle::Balanced).unwrap();
+        let rendered = PlanCalculator::plan(&data, &settings).unwrap().render_in(Lang::PtBr);
+        assert!(rendered.contains("Objetivo: Perder peso (0,50 kg/semana)"));
+        assert!(rendered.contains("Meta calórica diária: 2121,98 kcal/dia"));
+        assert!(rendered.ends_with("Gordura: 71 g (30%)"));
+    }
+
+    #[test]
+    fn test_render_projection() {
+        use crate::common::date::Date;
+        use crate::metabolism::activity::ActivityLevel;
+        use crate::metabolism::tmb::TmbData;
+        use crate::planning::projection::{ProjectionCalculator, ProjectionSettings};
+        let data = TmbData::new(70.0, 1.75, 25, Sex::Male).unwrap();
+        let settings = ProjectionSettings::new(2000.0, ActivityLevel::Sedentary, 10, Date::new(2025, 1, 1).unwrap())
+            .unwrap()
+            .with_target(crate::bmi::calculator::BmiCategory::Underweight);
+        let rendered = ProjectionCalculator::project(&data, &settings).unwrap().render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "Day | Date | Weight (kg) | BMI | PGC (%)");
+        assert_eq!(lines[1], "0 | 2025-01-01 | 70.0 | 22.9 | 17.0");
+        assert!(lines[3].starts_with("10 | 2025-01-11 |"));
+        assert_eq!(lines[4], "Underweight not reached within 10 days");
+    }
+
+    #[test]
+    fn test_render_pediatric_bmi() {
+        let data = BmiData::new(16.4 * 1.4 * 1.4, 1.4).unwrap();
+        let rendered = BmiCalculator::assess_for_age(&data, 10, Sex::Male).unwrap().render();
+        let expected = "BMI: 16.40 kg/m²\n\
+                        BMI-for-age z-score: 0.00 (percentile 50.0)\n\
+                        Classification: Normal\n\
+                        Growth reference: WHO 2006/2007\n\
+                        Risk: Low";
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(Lang::EnUs, 1723.861, Unit::KilocaloriesPerDay), "1723.86 kcal/day");
+        assert_eq!(format_value(Lang::PtBr, 1723.861, Unit::KilocaloriesPerDay), "1723,86 kcal/dia");
+        assert_eq!(format_value(Lang::EnUs, 18.134, Unit::Percent), "18.13%");
+    }
+}
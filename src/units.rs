@@ -0,0 +1,232 @@
+//! Strongly-typed units of mass and length with metric/imperial conversion.
+//!
+//! Calculators store metric values (kg, m, cm); every data constructor takes
+//! `impl Into<Kilograms>`, `impl Into<Meters>` or `impl Into<Centimeters>`, so
+//! plain `f32` metric values and any of these newtypes are accepted. Imperial
+//! input is thus converted once, at the boundary:
+//!
+//! ```
+//! use fourv_saude::bmi::calculator::BmiData;
+//! use fourv_saude::units::{FeetInches, Pounds};
+//!
+//! let data = BmiData::new(Pounds(154.0), FeetInches::new(5, 10.0)).unwrap();
+//! assert!((data.weight - 69.85).abs() < 0.01);
+//! ```
+//!
+//! Conversions use the exact international definitions (1 lb = 0.45359237 kg,
+//! 1 in = 2.54 cm) and are computed in f64.
+
+use std::fmt;
+
+const KG_PER_POUND: f64 = 0.453_592_37;
+const CM_PER_INCH: f64 = 2.54;
+const INCHES_PER_FOOT: f64 = 12.0;
+
+/// Mass in kilograms.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Kilograms(pub f32);
+
+/// Mass in avoirdupois pounds.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Pounds(pub f32);
+
+/// Length in meters.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Meters(pub f32);
+
+/// Length in centimeters.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Centimeters(pub f32);
+
+/// Length in inches.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Inches(pub f32);
+
+/// Length in feet and inches, e.g. 5'10".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeetInches {
+    pub feet: u32,
+    pub inches: f32,
+}
+
+impl FeetInches {
+    pub fn new(feet: u32, inches: f32) -> Self {
+        FeetInches { feet, inches }
+    }
+
+    fn total_inches(&self) -> f64 {
+        self.feet as f64 * INCHES_PER_FOOT + self.inches as f64
+    }
+}
+
+// Bare numbers are read as metric, so existing call sites keep working.
+impl From<f32> for Kilograms {
+    fn from(value: f32) -> Self {
+        Kilograms(value)
+    }
+}
+
+impl From<f32> for Meters {
+    fn from(value: f32) -> Self {
+        Meters(value)
+    }
+}
+
+impl From<f32> for Centimeters {
+    fn from(value: f32) -> Self {
+        Centimeters(value)
+    }
+}
+
+impl From<Pounds> for Kilograms {
+    fn from(value: Pounds) -> Self {
+        Kilograms((value.0 as f64 * KG_PER_POUND) as f32)
+    }
+}
+
+impl From<Kilograms> for Pounds {
+    fn from(value: Kilograms) -> Self {
+        Pounds((value.0 as f64 / KG_PER_POUND) as f32)
+    }
+}
+
+impl From<Centimeters> for Meters {
+    fn from(value: Centimeters) -> Self {
+        Meters((value.0 as f64 / 100.0) as f32)
+    }
+}
+
+impl From<Meters> for Centimeters {
+    fn from(value: Meters) -> Self {
+        Centimeters((value.0 as f64 * 100.0) as f32)
+    }
+}
+
+impl From<Inches> for Centimeters {
+    fn from(value: Inches) -> Self {
+        Centimeters((value.0 as f64 * CM_PER_INCH) as f32)
+    }
+}
+
+impl From<Centimeters> for Inches {
+    fn from(value: Centimeters) -> Self {
+        Inches((value.0 as f64 / CM_PER_INCH) as f32)
+    }
+}
+
+impl From<Inches> for Meters {
+    fn from(value: Inches) -> Self {
+        Meters((value.0 as f64 * CM_PER_INCH / 100.0) as f32)
+    }
+}
+
+impl From<Meters> for Inches {
+    fn from(value: Meters) -> Self {
+        Inches((value.0 as f64 * 100.0 / CM_PER_INCH) as f32)
+    }
+}
+
+impl From<FeetInches> for Inches {
+    fn from(value: FeetInches) -> Self {
+        Inches(value.total_inches() as f32)
+    }
+}
+
+impl From<FeetInches> for Centimeters {
+    fn from(value: FeetInches) -> Self {
+        Centimeters((value.total_inches() * CM_PER_INCH) as f32)
+    }
+}
+
+impl From<FeetInches> for Meters {
+    fn from(value: FeetInches) -> Self {
+        Meters((value.total_inches() * CM_PER_INCH / 100.0) as f32)
+    }
+}
+
+impl From<Inches> for FeetInches {
+    fn from(value: Inches) -> Self {
+        let total = value.0 as f64;
+        let feet = (total / INCHES_PER_FOOT).floor();
+        FeetInches {
+            feet: feet as u32,
+            inches: (total - feet * INCHES_PER_FOOT) as f32,
+        }
+    }
+}
+
+impl From<Meters> for FeetInches {
+    fn from(value: Meters) -> Self {
+        FeetInches::from(Inches::from(value))
+    }
+}
+
+impl fmt::Display for Kilograms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} kg", self.0)
+    }
+}
+
+impl fmt::Display for Pounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} lb", self.0)
+    }
+}
+
+impl fmt::Display for Meters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} m", self.0)
+    }
+}
+
+impl fmt::Display for Centimeters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} cm", self.0)
+    }
+}
+
+impl fmt::Display for Inches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1} in", self.0)
+    }
+}
+
+impl fmt::Display for FeetInches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}'{:.1}\"", self.feet, self.inches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pounds_to_kilograms() {
+        let kg = Kilograms::from(Pounds(160.0));
+        assert!((kg.0 - 72.5748).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_feet_inches_to_meters() {
+        let m = Meters::from(FeetInches::new(5, 10.0));
+        assert!((m.0 - 1.778).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let kg = Kilograms(70.0);
+        assert!((Kilograms::from(Pounds::from(kg)).0 - 70.0).abs() < 1e-5);
+        let cm = Centimeters(175.0);
+        assert!((Centimeters::from(Inches::from(cm)).0 - 175.0).abs() < 1e-4);
+        let fi = FeetInches::from(Meters(1.778));
+        assert_eq!(fi.feet, 5);
+        assert!((fi.inches - 10.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Kilograms(70.5).to_string(), "70.5 kg");
+        assert_eq!(FeetInches::new(5, 10.0).to_string(), "5'10.0\"");
+    }
+}
@@ -1,21 +1,175 @@
-/// Enum representing the gender of the user.
-#[derive(Clone)]
-pub enum Gender {
-    Male,
-    Female,
-}
+use crate::assessment::{Assessment, Classification, Formula, ReferenceRange, RiskLevel, Unit};
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::units::Centimeters;
+
+/// Kept as an alias of the shared [`Sex`](crate::common::sex::Sex) type.
+pub use crate::common::sex::Sex as Gender;
 
 /// Struct to hold the input data for WHR calculation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WhrData {
     pub waist_circumference: f32, // in centimeters
     pub hip_circumference: f32,   // in centimeters
     pub gender: Gender,
 }
 
+impl WhrData {
+    /// Creates validated WHR input, rejecting non-finite, non-positive or implausible values.
+    pub fn new(
+        waist_circumference: impl Into<Centimeters>,
+        hip_circumference: impl Into<Centimeters>,
+        gender: Gender,
+    ) -> Result<Self, HealthError> {
+        let data = WhrData {
+            waist_circumference: waist_circumference.into().0,
+            hip_circumference: hip_circumference.into().0,
+            gender,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that both circumferences (cm) are plausible measurements.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("waist", self.waist_circumference, error::CIRCUMFERENCE_RANGE_CM)?;
+        error::validate_measurement("hip", self.hip_circumference, error::CIRCUMFERENCE_RANGE_CM)?;
+        Ok(())
+    }
+}
+
 /// Trait for WHR calculation and evaluation.
 pub trait WhrCalculatorTrait {
     fn calculate(data: &WhrData) -> f32;
     fn evaluate(whr: f32, gender: &Gender) -> String;
+
+    /// Validates the input and calculates the WHR, never returning NaN or infinity.
+    fn try_calculate(data: &WhrData) -> Result<f32, HealthError> {
+        data.validate()?;
+        error::ensure_finite("whr", Self::calculate(data))
+    }
+
+    /// Validates the input, calculates the WHR and returns its cardiovascular risk as a typed result.
+    fn assess(data: &WhrData) -> Result<WhrAssessment, HealthError> {
+        let whr = Self::try_calculate(data)?;
+        let cutoff = risk_cutoff(&data.gender);
+        let risk = if whr > cutoff { RiskLevel::High } else { RiskLevel::Low };
+        Ok(Assessment::new(
+            whr,
+            Unit::Ratio,
+            risk,
+            ReferenceRange::new(None, Some(cutoff)).with_inclusive_max(),
+            Formula::WaistToHip,
+        ))
+    }
+
+    /// Graded cardiovascular risk of the WHR for the sex and age group.
+    /// `whr` must be finite: NaN falls through to `VeryHigh`.
+    fn classify(whr: f32, gender: &Gender, age: u32) -> WhrRiskCategory {
+        let bands = risk_bands(gender, age);
+        match whr {
+            x if x < bands.moderate => WhrRiskCategory::Low,
+            x if x < bands.high => WhrRiskCategory::Moderate,
+            x if x <= bands.very_high_above => WhrRiskCategory::High,
+            _ => WhrRiskCategory::VeryHigh,
+        }
+    }
+
+    /// Grades the WHR, rejecting a non-finite ratio or an implausible age.
+    fn try_classify(whr: f32, gender: &Gender, age: u32) -> Result<WhrRiskCategory, HealthError> {
+        error::ensure_finite("whr", whr)?;
+        let age = error::validate_age(age)?;
+        Ok(Self::classify(whr, gender, age))
+    }
+
+    /// Validates the input, calculates the WHR and grades its risk for the age.
+    fn assess_for_age(data: &WhrData, age: u32) -> Result<WhrGradedAssessment, HealthError> {
+        let age = error::validate_age(age)?;
+        let whr = Self::try_calculate(data)?;
+        Ok(Assessment::new(
+            whr,
+            Unit::Ratio,
+            Self::classify(whr, &data.gender, age),
+            ReferenceRange::new(None, Some(risk_bands(&data.gender, age).moderate)),
+            Formula::WaistToHip,
+        ))
+    }
+}
+
+/// Graded WHR risk band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WhrRiskCategory {
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+}
+
+impl WhrRiskCategory {
+    /// Risk level of the band.
+    pub fn level(&self) -> RiskLevel {
+        match self {
+            WhrRiskCategory::Low => RiskLevel::Low,
+            WhrRiskCategory::Moderate => RiskLevel::Moderate,
+            WhrRiskCategory::High => RiskLevel::High,
+            WhrRiskCategory::VeryHigh => RiskLevel::VeryHigh,
+        }
+    }
+}
+
+impl Classification for WhrRiskCategory {
+    fn message(&self) -> Message {
+        Message::Risk(self.level())
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(self.level())
+    }
+}
+
+/// Typed result of a WHR assessment graded by age.
+pub type WhrGradedAssessment = Assessment<WhrRiskCategory>;
+
+/// WHR limits of the graded risk bands for one sex and age group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WhrRiskBands {
+    /// Start of the moderate band.
+    pub moderate: f32,
+    /// Start of the high band.
+    pub high: f32,
+    /// WHR above which the risk is very high.
+    pub very_high_above: f32,
+}
+
+/// Risk bands by sex and age group (Bray & Gray, 1988). The chart covers 20 to 69
+/// years; younger adults use the 20-29 row and older ones the 60-69 row.
+pub fn risk_bands(gender: &Gender, age: u32) -> WhrRiskBands {
+    let group = (age.clamp(20, 69) - 20) / 10;
+    let (moderate, high, very_high_above) = match (gender, group) {
+        (Gender::Male, 0) => (0.83, 0.89, 0.94),
+        (Gender::Male, 1) => (0.84, 0.92, 0.96),
+        (Gender::Male, 2) => (0.88, 0.96, 1.00),
+        (Gender::Male, 3) => (0.90, 0.97, 1.02),
+        (Gender::Male, _) => (0.91, 0.99, 1.03),
+        (Gender::Female, 0) => (0.71, 0.78, 0.82),
+        (Gender::Female, 1) => (0.72, 0.79, 0.84),
+        (Gender::Female, 2) => (0.73, 0.80, 0.87),
+        (Gender::Female, 3) => (0.74, 0.82, 0.88),
+        (Gender::Female, _) => (0.76, 0.84, 0.90),
+    };
+    WhrRiskBands { moderate, high, very_high_above }
+}
+
+/// Typed result of a WHR assessment; the category is the risk level itself.
+pub type WhrAssessment = Assessment<RiskLevel>;
+
+/// WHR above which cardiovascular risk is higher (WHO): 0.90 for men, 0.85 for women.
+pub fn risk_cutoff(gender: &Gender) -> f32 {
+    match gender {
+        Gender::Male => 0.90,
+        Gender::Female => 0.85,
+    }
 }
 
 /// Implementation of the WHR calculator.
@@ -117,4 +271,76 @@ mod tests {
         assert!(result.contains("Lower risk"));
         assert!(result.contains("WHR: 0.80"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_new_rejects_zero_hip() {
+        let result = WhrData::new(90.0, 0.0, Gender::Male);
+        assert_eq!(result.err(), Some(HealthError::Zero { field: "hip" }));
+    }
+
+    #[test]
+    fn test_assess_female_high_risk() {
+        let data = WhrData::new(90.0, 100.0, Gender::Female).unwrap();
+        let assessment = WhrCalculator::assess(&data).unwrap();
+        assert!((assessment.value - 0.9).abs() < 1e-6);
+        assert_eq!(assessment.unit, Unit::Ratio);
+        assert_eq!(assessment.category, RiskLevel::High);
+        assert_eq!(assessment.reference_range, ReferenceRange::new(None, Some(0.85)).with_inclusive_max());
+        assert_eq!(assessment.formula, Formula::WaistToHip);
+        assert_eq!(assessment.risk, Some(RiskLevel::High));
+    }
+
+    #[test]
+    fn test_assess_cutoff_is_inside_range() {
+        let data = WhrData::new(90.0, 100.0, Gender::Male).unwrap();
+        let assessment = WhrCalculator::assess(&data).unwrap();
+        assert_eq!(assessment.category, RiskLevel::Low);
+        assert!(assessment.reference_range.contains(assessment.value));
+    }
+
+    #[test]
+    fn test_try_classify_rejects_nan() {
+        let result = WhrCalculator::try_classify(f32::NAN, &Gender::Male, 40);
+        assert_eq!(result, Err(HealthError::InvalidResult { calculation: "whr" }));
+        assert_eq!(WhrCalculator::try_classify(0.85, &Gender::Male, 45), Ok(WhrRiskCategory::Low));
+    }
+
+    #[test]
+    fn test_classify_by_age_group() {
+        assert_eq!(WhrCalculator::classify(0.85, &Gender::Male, 25), WhrRiskCategory::Moderate);
+        assert_eq!(WhrCalculator::classify(0.85, &Gender::Male, 45), WhrRiskCategory::Low);
+        assert_eq!(WhrCalculator::classify(0.94, &Gender::Male, 25), WhrRiskCategory::High);
+        assert_eq!(WhrCalculator::classify(0.95, &Gender::Male, 25), WhrRiskCategory::VeryHigh);
+        assert_eq!(WhrCalculator::classify(0.80, &Gender::Female, 65), WhrRiskCategory::Moderate);
+    }
+
+    #[test]
+    fn test_risk_bands_outside_chart_ages() {
+        assert_eq!(risk_bands(&Gender::Female, 18), risk_bands(&Gender::Female, 25));
+        assert_eq!(risk_bands(&Gender::Male, 80), risk_bands(&Gender::Male, 65));
+    }
+
+    #[test]
+    fn test_assess_for_age() {
+        let data = WhrData::new(90.0, 100.0, Gender::Female).unwrap();
+        let assessment = WhrCalculator::assess_for_age(&data, 55).unwrap();
+        assert_eq!(assessment.category, WhrRiskCategory::VeryHigh);
+        assert_eq!(assessment.reference_range, ReferenceRange::new(None, Some(0.74)));
+        assert_eq!(assessment.risk, Some(RiskLevel::VeryHigh));
+    }
+
+    #[test]
+    fn test_new_with_inches() {
+        use crate::units::Inches;
+        let data = WhrData::new(Inches(34.0), Inches(40.0), Gender::Male).unwrap();
+        assert!((data.waist_circumference - 86.36).abs() < 1e-3);
+        assert!((WhrCalculator::calculate(&data) - 0.85).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_try_calculate_valid_data() {
+        let data = WhrData::new(90.0, 100.0, Gender::Male).unwrap();
+        let whr = WhrCalculator::try_calculate(&data).unwrap();
+        assert!((whr - 0.9).abs() < 1e-6);
+    }
+}
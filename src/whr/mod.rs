@@ -1 +1,3 @@
-pub mod calculator; // This is needed to expose the calculator module
\ No newline at end of file
+pub mod calculator; // This is needed to expose the calculator module
+pub mod waist; // Waist-to-height ratio and waist circumference thresholds
+pub mod shape; // ABSI, Body Roundness Index and Conicity Index
@@ -0,0 +1,262 @@
+//! Body shape indices: A Body Shape Index (ABSI), Body Roundness Index (BRI)
+//! and Conicity Index.
+//!
+//! All three combine the waist with weight and height to describe central
+//! adiposity independently of the BMI. ABSI is read as a z-score against age
+//! and sex reference values (Krakauer & Krakauer, 2012).
+
+use crate::assessment::{Classification, RiskLevel};
+use crate::bmi::calculator::{BmiCalculator, BmiData};
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::units::{Centimeters, Kilograms, Meters};
+use crate::whr::calculator::{Gender, WhrData};
+
+/// Youngest age covered by the ABSI reference values, in years.
+pub const ABSI_MIN_AGE_YEARS: u32 = 18;
+
+/// Input of the body shape indices; weight in kg, height in m, waist in cm.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyShapeData {
+    pub weight: f32,
+    pub height: f32,
+    pub waist_circumference: f32,
+    pub age: u32,
+    pub gender: Gender,
+}
+
+impl BodyShapeData {
+    /// Creates validated input.
+    pub fn new(
+        weight: impl Into<Kilograms>,
+        height: impl Into<Meters>,
+        waist_circumference: impl Into<Centimeters>,
+        age: u32,
+        gender: Gender,
+    ) -> Result<Self, HealthError> {
+        let data = BodyShapeData {
+            weight: weight.into().0,
+            height: height.into().0,
+            waist_circumference: waist_circumference.into().0,
+            age,
+            gender,
+        };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Combines the weight and height of a BMI input with the waist and sex of a WHR input.
+    pub fn from_measurements(bmi: &BmiData, whr: &WhrData, age: u32) -> Result<Self, HealthError> {
+        Self::new(bmi.weight, bmi.height, whr.waist_circumference, age, whr.gender)
+    }
+
+    /// Checks the measurements and that the age is covered by the ABSI reference.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("weight", self.weight, error::WEIGHT_RANGE_KG)?;
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        error::validate_measurement("waist", self.waist_circumference, error::CIRCUMFERENCE_RANGE_CM)?;
+        error::validate_age(self.age)?;
+        if self.age < ABSI_MIN_AGE_YEARS {
+            return Err(HealthError::Implausible {
+                field: "age",
+                value: self.age as f32,
+                min: ABSI_MIN_AGE_YEARS as f32,
+                max: error::MAX_AGE_YEARS as f32,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Mortality risk by ABSI z-score, split at the quintiles of the reference population.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AbsiRiskCategory {
+    VeryLow,
+    Low,
+    Average,
+    High,
+    VeryHigh,
+}
+
+impl Classification for AbsiRiskCategory {
+    fn message(&self) -> Message {
+        Message::Absi(*self)
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            AbsiRiskCategory::VeryLow | AbsiRiskCategory::Low => RiskLevel::Low,
+            AbsiRiskCategory::Average => RiskLevel::Moderate,
+            AbsiRiskCategory::High => RiskLevel::High,
+            AbsiRiskCategory::VeryHigh => RiskLevel::VeryHigh,
+        })
+    }
+}
+
+/// Mean and standard deviation of ABSI at an age.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbsiReference {
+    pub age: u32,
+    pub mean: f32,
+    pub sd: f32,
+}
+
+const fn reference(age: u32, mean: f32, sd: f32) -> AbsiReference {
+    AbsiReference { age, mean, sd }
+}
+
+// Rounded knots of the NHANES 1999-2004 reference, suitable for screening.
+const MALE_ABSI: &[AbsiReference] = &[
+    reference(18, 0.0790, 0.0038), reference(25, 0.0797, 0.0038), reference(35, 0.0808, 0.0038),
+    reference(45, 0.0820, 0.0039), reference(55, 0.0833, 0.0040), reference(65, 0.0844, 0.0041),
+    reference(75, 0.0852, 0.0042), reference(85, 0.0858, 0.0043),
+];
+
+const FEMALE_ABSI: &[AbsiReference] = &[
+    reference(18, 0.0753, 0.0049), reference(25, 0.0763, 0.0050), reference(35, 0.0779, 0.0051),
+    reference(45, 0.0794, 0.0052), reference(55, 0.0810, 0.0053), reference(65, 0.0826, 0.0054),
+    reference(75, 0.0839, 0.0055), reference(85, 0.0846, 0.0056),
+];
+
+/// Body shape indices of one person.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BodyShape {
+    /// A Body Shape Index, in m^(11/6)·kg^(-2/3).
+    pub absi: f32,
+    pub absi_z: f32,
+    pub absi_risk: AbsiRiskCategory,
+    /// Body Roundness Index, from about 1 (lean) to 16 and above.
+    pub bri: f32,
+    /// Conicity Index, from 1.0 (cylinder) to about 1.73 (double cone).
+    pub conicity_index: f32,
+}
+
+/// Calculator for the body shape indices.
+pub struct BodyShapeCalculator;
+
+impl BodyShapeCalculator {
+    /// ABSI = waist (m) / (BMI^(2/3) × height (m)^(1/2)).
+    pub fn absi(data: &BodyShapeData) -> f32 {
+        let bmi = BmiCalculator::calculate(&BmiData { weight: data.weight, height: data.height });
+        Meters::from(Centimeters(data.waist_circumference)).0 / (bmi.powf(2.0 / 3.0) * data.height.sqrt())
+    }
+
+    /// BRI = 364.2 − 365.5 × √(1 − (waist / 2π)² / (height / 2)²), lengths in m.
+    pub fn bri(data: &BodyShapeData) -> f32 {
+        let waist = Meters::from(Centimeters(data.waist_circumference)).0;
+        let eccentricity = 1.0 - (waist / (2.0 * std::f32::consts::PI)).powi(2) / (0.5 * data.height).powi(2);
+        364.2 - 365.5 * eccentricity.sqrt()
+    }
+
+    /// Conicity Index = waist (m) / (0.109 × √(weight (kg) / height (m))).
+    pub fn conicity_index(data: &BodyShapeData) -> f32 {
+        Meters::from(Centimeters(data.waist_circumference)).0 / (0.109 * (data.weight / data.height).sqrt())
+    }
+
+    /// ABSI mean and standard deviation at an age, interpolated between the table's knots.
+    /// Ages past the last knot use its values.
+    pub fn absi_reference(gender: Gender, age: u32) -> AbsiReference {
+        let table = match gender {
+            Gender::Male => MALE_ABSI,
+            Gender::Female => FEMALE_ABSI,
+        };
+        let upper = table.iter().position(|knot| knot.age >= age).unwrap_or(table.len() - 1);
+        if upper == 0 || table[upper].age <= age {
+            return table[upper];
+        }
+        let (a, b) = (table[upper - 1], table[upper]);
+        let t = (age - a.age) as f32 / (b.age - a.age) as f32;
+        let lerp = |x: f32, y: f32| x + (y - x) * t;
+        reference(age, lerp(a.mean, b.mean), lerp(a.sd, b.sd))
+    }
+
+    /// Z-score of an ABSI value for the age and sex.
+    pub fn absi_z_score(absi: f32, gender: Gender, age: u32) -> f32 {
+        let reference = Self::absi_reference(gender, age);
+        (absi - reference.mean) / reference.sd
+    }
+
+    /// Classifies an ABSI z-score (Krakauer & Krakauer, 2012).
+    pub fn classify_absi(z: f32) -> AbsiRiskCategory {
+        match z {
+            x if x < -0.868 => AbsiRiskCategory::VeryLow,
+            x if x < -0.272 => AbsiRiskCategory::Low,
+            x if x < 0.229 => AbsiRiskCategory::Average,
+            x if x < 0.798 => AbsiRiskCategory::High,
+            _ => AbsiRiskCategory::VeryHigh,
+        }
+    }
+
+    /// Validates the input and calculates every index, never returning NaN or infinity.
+    pub fn calculate(data: &BodyShapeData) -> Result<BodyShape, HealthError> {
+        data.validate()?;
+        let absi = error::ensure_finite("absi", Self::absi(data))?;
+        // A waist wider than π × height has no elliptical body shape
+        let bri = error::ensure_finite("bri", Self::bri(data))?;
+        let conicity_index = error::ensure_finite("conicity index", Self::conicity_index(data))?;
+        let absi_z = Self::absi_z_score(absi, data.gender, data.age);
+        Ok(BodyShape {
+            absi,
+            absi_z,
+            absi_risk: Self::classify_absi(absi_z),
+            bri,
+            conicity_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn male() -> BodyShapeData {
+        BodyShapeData::new(70.0, 1.75, 85.0, 40, Gender::Male).unwrap()
+    }
+
+    #[test]
+    fn test_indices() {
+        // BMI 22.86: 0.85 / (22.86^(2/3) × 1.75^(1/2)) = 0.0797
+        let shape = BodyShapeCalculator::calculate(&male()).unwrap();
+        assert!((shape.absi - 0.0797).abs() < 1e-4);
+        assert!((shape.bri - 3.09).abs() < 0.02);
+        assert!((shape.conicity_index - 1.233).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_absi_z_score() {
+        // Reference at 40: mean 0.0814, sd 0.00385
+        let shape = BodyShapeCalculator::calculate(&male()).unwrap();
+        assert!((shape.absi_z + 0.42).abs() < 0.02);
+        assert_eq!(shape.absi_risk, AbsiRiskCategory::Low);
+    }
+
+    #[test]
+    fn test_absi_reference_interpolation() {
+        let reference = BodyShapeCalculator::absi_reference(Gender::Female, 30);
+        assert!((reference.mean - 0.0771).abs() < 1e-6);
+        assert_eq!(BodyShapeCalculator::absi_reference(Gender::Male, 95).mean, 0.0858);
+    }
+
+    #[test]
+    fn test_classify_absi() {
+        assert_eq!(BodyShapeCalculator::classify_absi(-1.0), AbsiRiskCategory::VeryLow);
+        assert_eq!(BodyShapeCalculator::classify_absi(0.0), AbsiRiskCategory::Average);
+        assert_eq!(BodyShapeCalculator::classify_absi(0.8), AbsiRiskCategory::VeryHigh);
+    }
+
+    #[test]
+    fn test_from_measurements() {
+        let bmi = BmiData::new(70.0, 1.75).unwrap();
+        let whr = WhrData::new(85.0, 100.0, Gender::Male).unwrap();
+        let data = BodyShapeData::from_measurements(&bmi, &whr, 40).unwrap();
+        assert_eq!(BodyShapeCalculator::calculate(&data), BodyShapeCalculator::calculate(&male()));
+    }
+
+    #[test]
+    fn test_rejects_minors() {
+        let result = BodyShapeData::new(50.0, 1.60, 70.0, 15, Gender::Female);
+        assert!(matches!(result, Err(HealthError::Implausible { field: "age", .. })));
+    }
+}
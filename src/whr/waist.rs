@@ -0,0 +1,357 @@
+//! Waist-to-height ratio (WHtR) and waist circumference risk thresholds.
+//!
+//! WHtR ("keep your waist under half your height") works for both sexes and
+//! all statures. Absolute waist thresholds depend on sex and, for the IDF,
+//! on ethnic group.
+
+use std::str::FromStr;
+
+use crate::assessment::{Assessment, Classification, Formula, ReferenceRange, RiskLevel, Standard, Unit};
+use crate::error::{self, HealthError};
+use crate::i18n::Message;
+use crate::units::{Centimeters, Meters};
+use crate::whr::calculator::Gender;
+
+/// Healthy WHtR range (NICE, 2022).
+pub const HEALTHY_WHTR_RANGE: ReferenceRange = ReferenceRange { min: Some(0.4), max: Some(0.5), max_inclusive: false };
+
+/// Input of the waist-to-height ratio.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WhtrData {
+    pub waist_circumference: f32, // in centimeters
+    pub height: f32,              // in meters
+}
+
+impl WhtrData {
+    /// Creates validated input.
+    pub fn new(waist_circumference: impl Into<Centimeters>, height: impl Into<Meters>) -> Result<Self, HealthError> {
+        let data = WhtrData { waist_circumference: waist_circumference.into().0, height: height.into().0 };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that the waist (cm) and height (m) are plausible measurements.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("waist", self.waist_circumference, error::CIRCUMFERENCE_RANGE_CM)?;
+        error::validate_measurement("height", self.height, error::HEIGHT_RANGE_M)?;
+        Ok(())
+    }
+}
+
+/// Classification of the WHtR.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WhtrCategory {
+    /// Below 0.4: may indicate underweight.
+    Low,
+    Healthy,
+    IncreasedRisk,
+    HighRisk,
+}
+
+impl Classification for WhtrCategory {
+    fn message(&self) -> Message {
+        Message::Whtr(*self)
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            WhtrCategory::Low => RiskLevel::Moderate,
+            WhtrCategory::Healthy => RiskLevel::Low,
+            WhtrCategory::IncreasedRisk => RiskLevel::High,
+            WhtrCategory::HighRisk => RiskLevel::VeryHigh,
+        })
+    }
+}
+
+/// Typed result of a WHtR assessment.
+pub type WhtrAssessment = Assessment<WhtrCategory>;
+
+/// Calculator for the waist-to-height ratio.
+pub struct WhtrCalculator;
+
+impl WhtrCalculator {
+    /// Waist divided by height, both in the same unit.
+    pub fn calculate(data: &WhtrData) -> f32 {
+        data.waist_circumference / Centimeters::from(Meters(data.height)).0
+    }
+
+    /// Validates the input and calculates the WHtR, never returning NaN or infinity.
+    pub fn try_calculate(data: &WhtrData) -> Result<f32, HealthError> {
+        data.validate()?;
+        error::ensure_finite("whtr", Self::calculate(data))
+    }
+
+    /// Classifies the WHtR: healthy from 0.4, increased risk from 0.5 and high risk from 0.6.
+    pub fn classify(whtr: f32) -> WhtrCategory {
+        match whtr {
+            x if x < 0.4 => WhtrCategory::Low,
+            x if x < 0.5 => WhtrCategory::Healthy,
+            x if x < 0.6 => WhtrCategory::IncreasedRisk,
+            _ => WhtrCategory::HighRisk,
+        }
+    }
+
+    /// Validates the input, calculates and classifies the WHtR as a typed result.
+    pub fn assess(data: &WhtrData) -> Result<WhtrAssessment, HealthError> {
+        let whtr = Self::try_calculate(data)?;
+        Ok(Assessment::new(whtr, Unit::Ratio, Self::classify(whtr), HEALTHY_WHTR_RANGE, Formula::WaistToHeight))
+    }
+}
+
+/// Population groups with their own IDF waist cut-offs (2006).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdfPopulation {
+    /// Europid, also used for Sub-Saharan African and Middle Eastern people.
+    #[default]
+    Europid,
+    /// South Asian, also used for South and Central American people.
+    SouthAsian,
+    Chinese,
+    Japanese,
+}
+
+impl FromStr for IdfPopulation {
+    type Err = HealthError;
+
+    /// Parses `europid`, `south-asian`, `chinese` and `japanese`, plus the
+    /// groups the IDF assigns to them (`african`, `middle-eastern`, `latin-american`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "europid" | "european" | "african" | "subsaharanafrican" | "middleeastern" | "easternmediterranean" => {
+                Ok(IdfPopulation::Europid)
+            }
+            "southasian" | "asian" | "latinamerican" | "southamerican" | "centralamerican" => {
+                Ok(IdfPopulation::SouthAsian)
+            }
+            "chinese" => Ok(IdfPopulation::Chinese),
+            "japanese" => Ok(IdfPopulation::Japanese),
+            _ => Err(HealthError::Unrecognized {
+                field: "population",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Published set of waist circumference cut-offs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WaistStandard {
+    /// WHO (2008): increased risk from 94/80 cm, substantially increased from 102/88 cm (men/women).
+    #[default]
+    Who,
+    /// NCEP ATP III (2001): abdominal obesity above 102/88 cm.
+    Ncep,
+    /// International Diabetes Federation (2006), ethnic-specific central obesity cut-offs.
+    Idf(IdfPopulation),
+}
+
+impl FromStr for WaistStandard {
+    type Err = HealthError;
+
+    /// Parses `who`, `ncep` (or `atp3`) and `idf` (Europid cut-offs).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let name: String = input.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+        match name.as_str() {
+            "who" | "oms" => Ok(WaistStandard::Who),
+            "ncep" | "atp3" | "atpiii" | "ncepatp3" | "ncepatpiii" => Ok(WaistStandard::Ncep),
+            "idf" => Ok(WaistStandard::Idf(IdfPopulation::Europid)),
+            _ => Err(HealthError::Unrecognized {
+                field: "waist standard",
+                input: input.trim().to_string(),
+            }),
+        }
+    }
+}
+
+/// Waist circumference cut-offs of a standard for one sex, in cm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaistCutOffs {
+    /// Start of the increased risk band, if the standard has one.
+    pub increased: Option<f32>,
+    /// Start of the substantially increased (or only) risk band.
+    pub substantially_increased: Option<f32>,
+    /// Whether a waist equal to a cut-off is already above it (NCEP uses "> 102 cm").
+    pub exclusive: bool,
+}
+
+impl WaistStandard {
+    /// Cut-offs for the given sex.
+    pub fn cut_offs(&self, gender: Gender) -> WaistCutOffs {
+        let (increased, substantially_increased, exclusive) = match (self, gender) {
+            (WaistStandard::Who, Gender::Male) => (Some(94.0), Some(102.0), false),
+            (WaistStandard::Who, Gender::Female) => (Some(80.0), Some(88.0), false),
+            (WaistStandard::Ncep, Gender::Male) => (None, Some(102.0), true),
+            (WaistStandard::Ncep, Gender::Female) => (None, Some(88.0), true),
+            (WaistStandard::Idf(population), gender) => {
+                let male = match population {
+                    IdfPopulation::Europid => 94.0,
+                    IdfPopulation::SouthAsian | IdfPopulation::Chinese | IdfPopulation::Japanese => 90.0,
+                };
+                let cut_off = match gender {
+                    Gender::Male => male,
+                    Gender::Female => 80.0,
+                };
+                (Some(cut_off), None, false)
+            }
+        };
+        WaistCutOffs { increased, substantially_increased, exclusive }
+    }
+}
+
+/// Input of the waist circumference thresholds.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaistData {
+    pub waist_circumference: f32, // in centimeters
+    pub gender: Gender,
+}
+
+impl WaistData {
+    /// Creates validated input.
+    pub fn new(waist_circumference: impl Into<Centimeters>, gender: Gender) -> Result<Self, HealthError> {
+        let data = WaistData { waist_circumference: waist_circumference.into().0, gender };
+        data.validate()?;
+        Ok(data)
+    }
+
+    /// Checks that the waist (cm) is a plausible measurement.
+    pub fn validate(&self) -> Result<(), HealthError> {
+        error::validate_measurement("waist", self.waist_circumference, error::CIRCUMFERENCE_RANGE_CM)?;
+        Ok(())
+    }
+}
+
+/// Risk of metabolic complications by waist circumference.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WaistRiskCategory {
+    Normal,
+    Increased,
+    SubstantiallyIncreased,
+}
+
+impl Classification for WaistRiskCategory {
+    fn message(&self) -> Message {
+        Message::WaistRisk(*self)
+    }
+
+    fn risk(&self) -> Option<RiskLevel> {
+        Some(match self {
+            WaistRiskCategory::Normal => RiskLevel::Low,
+            WaistRiskCategory::Increased => RiskLevel::High,
+            WaistRiskCategory::SubstantiallyIncreased => RiskLevel::VeryHigh,
+        })
+    }
+}
+
+/// Typed result of a waist circumference assessment.
+pub type WaistAssessment = Assessment<WaistRiskCategory>;
+
+/// Classifies the waist circumference against the chosen standard.
+pub struct WaistCalculator;
+
+impl WaistCalculator {
+    /// Category of the waist (cm) for the sex and standard.
+    pub fn classify(waist: f32, gender: Gender, standard: WaistStandard) -> WaistRiskCategory {
+        let cut_offs = standard.cut_offs(gender);
+        let above = |cut_off: Option<f32>| {
+            cut_off.is_some_and(|c| if cut_offs.exclusive { waist > c } else { waist >= c })
+        };
+        if above(cut_offs.substantially_increased) {
+            WaistRiskCategory::SubstantiallyIncreased
+        } else if above(cut_offs.increased) {
+            WaistRiskCategory::Increased
+        } else {
+            WaistRiskCategory::Normal
+        }
+    }
+
+    /// Validates the input and classifies the waist with the WHO cut-offs.
+    pub fn assess(data: &WaistData) -> Result<WaistAssessment, HealthError> {
+        Self::assess_with_standard(data, WaistStandard::Who)
+    }
+
+    /// Validates the input and classifies the waist, recording the standard in the result.
+    pub fn assess_with_standard(data: &WaistData, standard: WaistStandard) -> Result<WaistAssessment, HealthError> {
+        data.validate()?;
+        let waist = data.waist_circumference;
+        let cut_offs = standard.cut_offs(data.gender);
+        let upper = cut_offs.increased.or(cut_offs.substantially_increased);
+        let range = ReferenceRange::new(None, upper);
+        Ok(Assessment::new(
+            waist,
+            Unit::Centimeters,
+            Self::classify(waist, data.gender, standard),
+            if cut_offs.exclusive { range.with_inclusive_max() } else { range },
+            Formula::WaistCircumference,
+        )
+        .with_standard(Standard::Waist(standard)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whtr() {
+        let data = WhtrData::new(85.0, 1.75).unwrap();
+        assert!((WhtrCalculator::calculate(&data) - 0.4857).abs() < 1e-4);
+        assert_eq!(WhtrCalculator::assess(&data).unwrap().category, WhtrCategory::Healthy);
+    }
+
+    #[test]
+    fn test_classify_whtr() {
+        assert_eq!(WhtrCalculator::classify(0.39), WhtrCategory::Low);
+        assert_eq!(WhtrCalculator::classify(0.5), WhtrCategory::IncreasedRisk);
+        assert_eq!(WhtrCalculator::classify(0.6), WhtrCategory::HighRisk);
+    }
+
+    #[test]
+    fn test_who_waist_bands() {
+        assert_eq!(WaistCalculator::classify(93.9, Gender::Male, WaistStandard::Who), WaistRiskCategory::Normal);
+        assert_eq!(WaistCalculator::classify(94.0, Gender::Male, WaistStandard::Who), WaistRiskCategory::Increased);
+        assert_eq!(
+            WaistCalculator::classify(88.0, Gender::Female, WaistStandard::Who),
+            WaistRiskCategory::SubstantiallyIncreased
+        );
+        let data = WaistData::new(102.0, Gender::Male).unwrap();
+        let assessment = WaistCalculator::assess_with_standard(&data, WaistStandard::Ncep).unwrap();
+        assert!(assessment.reference_range.contains(assessment.value));
+    }
+
+    #[test]
+    fn test_ncep_is_exclusive() {
+        assert_eq!(WaistCalculator::classify(102.0, Gender::Male, WaistStandard::Ncep), WaistRiskCategory::Normal);
+        assert_eq!(
+            WaistCalculator::classify(102.5, Gender::Male, WaistStandard::Ncep),
+            WaistRiskCategory::SubstantiallyIncreased
+        );
+    }
+
+    #[test]
+    fn test_idf_ethnic_cut_offs() {
+        let asian = WaistStandard::Idf(IdfPopulation::SouthAsian);
+        assert_eq!(WaistCalculator::classify(91.0, Gender::Male, asian), WaistRiskCategory::Increased);
+        let europid = WaistStandard::Idf(IdfPopulation::Europid);
+        assert_eq!(WaistCalculator::classify(91.0, Gender::Male, europid), WaistRiskCategory::Normal);
+    }
+
+    #[test]
+    fn test_assess_records_standard() {
+        let data = WaistData::new(100.0, Gender::Male).unwrap();
+        let assessment = WaistCalculator::assess_with_standard(&data, WaistStandard::Ncep).unwrap();
+        assert_eq!(assessment.standard, Some(Standard::Waist(WaistStandard::Ncep)));
+        assert_eq!(assessment.reference_range, ReferenceRange::new(None, Some(102.0)).with_inclusive_max());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("NCEP ATP III".parse::<WaistStandard>(), Ok(WaistStandard::Ncep));
+        assert_eq!("latin-american".parse::<IdfPopulation>(), Ok(IdfPopulation::SouthAsian));
+        assert!("martian".parse::<IdfPopulation>().is_err());
+    }
+}
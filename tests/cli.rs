@@ -0,0 +1,373 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    // Reports follow the locale, so pin it for predictable output
+    Command::new(env!("CARGO_BIN_EXE_fourv_saude"))
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .env("LANG", "C")
+        .args(args)
+        .output()
+        .expect("failed to run binary")
+}
+
+#[test]
+fn cli_bmi_subcommand() {
+    let output = run(&["bmi", "--weight", "70", "--height", "1.75"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("BMI: 22.86 kg/m²"));
+    assert!(stdout.contains("Normal weight"));
+}
+
+#[test]
+fn cli_all_subcommand_includes_whr_when_measured() {
+    let output = run(&[
+        "all", "--weight", "70", "--height", "1.75", "--age", "30", "--sex", "M", "--waist", "90", "--hip", "100",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("BMI:"));
+    assert!(stdout.contains("Basal Metabolic Rate (TMB):"));
+    assert!(stdout.contains("Body Fat Percentage (PGC):"));
+    assert!(stdout.contains("Waist-to-Hip Ratio (WHR): 0.90"));
+}
+
+#[test]
+fn cli_tmb_with_formula() {
+    let output = run(&["tmb", "--weight", "70", "--height", "1.75", "--age", "25", "--sex", "M", "--formula", "mifflin"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Basal Metabolic Rate (TMB): 1673.75 kcal/day"));
+    assert!(stdout.contains("Formula: Mifflin-St Jeor"));
+    let katch = run(&["tmb", "--weight", "70", "--height", "1.75", "--age", "25", "--sex", "M", "--formula", "katch", "--lean-mass", "60"]);
+    assert!(String::from_utf8_lossy(&katch.stdout).contains("1666.00 kcal/day"));
+    let ignored = run(&["tmb", "--weight", "70", "--height", "1.75", "--age", "40", "--sex", "M", "--lean-mass", "50"]);
+    assert_eq!(ignored.status.code(), Some(2));
+}
+
+#[test]
+fn cli_tdee_shows_basal_and_total() {
+    let output = run(&["tdee", "--weight", "70", "--height", "1.75", "--age", "25", "--sex", "M", "--activity", "moderate"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Basal Metabolic Rate (TMB): 1723.86 kcal/day"));
+    assert!(stdout.contains("Total Daily Energy Expenditure (TDEE): 2671.98 kcal/day"));
+    assert_eq!(run(&["tdee", "--weight", "70", "--height", "1.75", "--age", "25", "--sex", "M"]).status.code(), Some(2));
+}
+
+#[test]
+fn cli_plan_refuses_unsafe_target() {
+    let base = ["plan", "--weight", "70", "--height", "1.75", "--age", "25", "--sex", "M", "--activity", "moderate", "--goal", "lose"];
+    let output = run(&base);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Daily calorie target: 2121.98 kcal/day"));
+    assert!(stdout.contains("Protein: 106 g (20%)"));
+    let unsafe_rate = run(&[&base[..], &["--rate", "1"]].concat());
+    assert_eq!(unsafe_rate.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&unsafe_rate.stderr).contains("below the safe minimum of 1724 kcal/day"));
+}
+
+#[test]
+fn cli_project_reports_target_date() {
+    let output = run(&[
+        "project", "--weight", "80", "--height", "1.75", "--age", "40", "--sex", "M", "--activity", "light",
+        "--intake", "1800", "--days", "365", "--start", "2025-01-01", "--target", "normal",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Day | Date | Weight (kg) | BMI | PGC (%)\n0 | 2025-01-01 | 80.0 | 26.1 |"));
+    assert!(stdout.contains("Normal weight reached on 2025-"));
+}
+
+#[test]
+fn cli_bmi_with_new_bmi_index() {
+    let output = run(&["bmi", "--weight", "100", "--height", "2.0", "--index", "trefethen"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Classification: Overweight"));
+    assert!(stdout.contains("New BMI (Trefethen): 22.98 kg/m²\nClassification: Normal weight"));
+}
+
+#[test]
+fn cli_pgc_with_navy_method() {
+    let output = run(&[
+        "pgc", "--method", "navy", "--height", "1.75", "--neck", "38", "--waist", "85", "--age", "30", "--sex", "M",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Formula: US Navy"));
+    assert!(stdout.contains("Classification: Fitness (by sex), Normal (by age group)"));
+
+    let without_hip = run(&["pgc", "--method", "navy", "--height", "1.65", "--neck", "32", "--waist", "75", "--age", "30", "--sex", "F"]);
+    assert_eq!(without_hip.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&without_hip.stderr).contains("hip is required"));
+}
+
+#[test]
+fn cli_pgc_with_skinfolds() {
+    let jp3 = ["pgc", "--method", "jp3", "--chest", "20", "--abdomen", "20", "--weight", "80", "--age", "30", "--sex", "M"];
+    let missing = run(&jp3);
+    assert_eq!(missing.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("thigh skinfold is required"));
+
+    let output = run(&[&jp3[..], &["--thigh", "20"]].concat());
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Body Fat Percentage (PGC): 17.95%"));
+    assert!(stdout.contains("Formula: Jackson-Pollock 3-site"));
+}
+
+#[test]
+fn cli_pgc_comparison() {
+    let output = run(&[
+        "pgc", "--method", "compare", "--weight", "70", "--height", "1.75", "--age", "30", "--sex", "M", "--neck", "38",
+        "--waist", "85",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for method in ["Deurenberg: ", "CUN-BAE: ", "Relative Fat Mass (RFM): ", "US Navy: ", "Spread: "] {
+        assert!(stdout.contains(method), "missing {method}");
+    }
+}
+
+#[test]
+fn cli_pgc_comparison_skips_implausible_estimator() {
+    let output = run(&[
+        "pgc", "--method", "compare", "--weight", "45", "--height", "1.80", "--age", "20", "--sex", "M", "--neck", "40",
+        "--waist", "60",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Left out (implausible result): CUN-BAE, US Navy"));
+}
+
+#[test]
+fn cli_composition_and_lean_mass_formula() {
+    let output = run(&["composition", "--weight", "70", "--height", "1.75", "--age", "30", "--sex", "M"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Fat mass: 12.69 kg (18.13%, Deurenberg)"));
+    assert!(stdout.contains("Normalized FFMI (1.80 m): "));
+
+    let output = run(&[
+        "tmb", "--weight", "70", "--height", "1.75", "--age", "25", "--sex", "M", "--formula", "katch", "--lean-mass", "boer",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    // 370 + 21.6 × 56.015
+    assert!(String::from_utf8_lossy(&output.stdout).contains("1579.92 kcal/day"));
+
+    let output = run(&["composition", "--weight", "225", "--height", "1.60", "--age", "40", "--sex", "M"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Lean body mass: Boer ") && !stdout.contains("James"));
+}
+
+#[test]
+fn cli_waist_with_population_and_height() {
+    let output = run(&["waist", "--waist", "92", "--sex", "M", "--height", "1.75", "--population", "south-asian"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Classification: Increased risk of metabolic complications"));
+    assert!(stdout.contains("Standard: IDF (South Asian)"));
+    assert!(stdout.contains("Waist-to-Height Ratio (WHtR): 0.53"));
+
+    let output = run(&["waist", "--waist", "92", "--sex", "M", "--standard", "ncep", "--population", "chinese"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn cli_shape_indices() {
+    let output = run(&["shape", "--weight", "70", "--height", "1.75", "--waist", "85", "--age", "40", "--sex", "M"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("A Body Shape Index (ABSI): 0.0798 (z-score -0.42: Low mortality risk)"));
+    assert!(stdout.contains("Conicity Index: 1.23"));
+
+    let output = run(&["shape", "--weight", "50", "--height", "1.60", "--waist", "70", "--age", "15", "--sex", "F"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn cli_whr_graded_by_age() {
+    let output = run(&["whr", "--waist", "85", "--hip", "100", "--sex", "M", "--age", "25"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Classification: Moderate cardiovascular risk"));
+    assert!(stdout.contains("Risk: Moderate"));
+}
+
+#[test]
+fn cli_bsa() {
+    let output = run(&["bsa", "--weight", "70", "--height", "1.75"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for formula in ["Mosteller: 1.84 m²", "DuBois: ", "Haycock: ", "Gehan-George: ", "Boyd: "] {
+        assert!(stdout.contains(formula), "missing {formula}");
+    }
+
+    let output = run(&["bsa", "--weight", "70", "--height", "1.75", "--formula", "boyd", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("\"formula\": \"Boyd\""));
+}
+
+#[test]
+fn cli_bmi_shows_weight_targets() {
+    let output = run(&["bmi", "--weight", "110", "--height", "1.75", "--sex", "M"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Healthy weight range: 56.66 - 76.56 kg"));
+    assert!(stdout.contains("Ideal body weight: Devine 70.46 kg, Robinson 68.91 kg, Miller 68.75 kg, Hamwi 72.02 kg"));
+    assert!(stdout.contains("Adjusted body weight: 86.28 kg"));
+}
+
+#[test]
+fn cli_bmi_short_adult_without_ideal_weight() {
+    let output = run(&["bmi", "--weight", "30", "--height", "0.95", "--sex", "F"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Healthy weight range: "));
+    assert!(!stdout.contains("Ideal body weight"));
+}
+
+#[test]
+fn cli_bmi_with_asian_standard() {
+    let output = run(&["bmi", "--weight", "70", "--height", "1.70", "--standard", "who-expert"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Classification: Overweight"));
+    assert!(stdout.contains("Standard: WHO expert consultation (Asian populations)"));
+}
+
+#[test]
+fn cli_bmi_for_age_under_20() {
+    let output = run(&["bmi", "--weight", "32", "--height", "1.40", "--age", "10", "--sex", "F", "--reference", "cdc"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("BMI-for-age z-score:"));
+    assert!(stdout.contains("Growth reference: CDC 2000"));
+    assert_eq!(run(&["bmi", "--weight", "32", "--height", "1.40", "--age", "10"]).status.code(), Some(2));
+}
+
+#[test]
+fn cli_bmi_for_age_months() {
+    let output = run(&["bmi", "--weight", "60", "--height", "1.70", "--age", "19", "--months", "6", "--sex", "M"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("BMI-for-age z-score:"));
+    let output = run(&["bmi", "--weight", "32", "--height", "1.40", "--age", "10", "--months", "25", "--sex", "F"]);
+    assert_eq!(output.status.code(), Some(2));
+    let output = run(&["bmi", "--weight", "70", "--height", "1.75", "--reference", "cdc"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--reference requires --age"));
+}
+
+#[test]
+fn cli_bmi_for_age_rejects_adult_options() {
+    let output = run(&["bmi", "--weight", "32", "--height", "1.40", "--age", "10", "--sex", "F", "--standard", "asian"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--standard applies only to adults"));
+    let output = run(&["bmi", "--weight", "32", "--height", "1.40", "--age", "10", "--sex", "F", "--index", "new"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--index applies only to adults"));
+}
+
+#[test]
+fn cli_interactive_stops_at_end_of_input() {
+    // `run` gives the child a closed stdin
+    let output = run(&["interactive"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("end of input"));
+}
+
+#[test]
+fn cli_invalid_input_exit_code() {
+    let output = run(&["bmi", "--weight", "70", "--height", "0"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("height must be greater than zero"));
+}
+
+#[test]
+fn cli_usage_error_exit_code() {
+    assert_eq!(run(&["tmb", "--weight", "70"]).status.code(), Some(2));
+    assert_eq!(run(&["frobnicate"]).status.code(), Some(2));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn cli_json_format() {
+    let output = run(&["bmi", "--weight", "70", "--height", "1.75", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+    assert_eq!(json["category"], "NormalWeight");
+    assert_eq!(json["unit"], "KilogramsPerSquareMeter");
+    assert_eq!(json["risk"], "Low");
+    assert!((json["value"].as_f64().unwrap() - 22.86).abs() < 0.01);
+}
+
+#[test]
+fn cli_unknown_format() {
+    assert_eq!(run(&["bmi", "--weight", "70", "--height", "1.75", "--format", "xml"]).status.code(), Some(2));
+}
+
+#[test]
+fn cli_batch_writes_output_csv() {
+    let dir = std::env::temp_dir().join(format!("fourv_saude_batch_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("people.csv");
+    let output_path = dir.join("results.csv");
+    std::fs::write(&input, "weight,height,age,sex,waist,hip\n70,1.75,30,M,90,100\n70,0,30,M,,\n").unwrap();
+
+    let output = run(&["batch", "--input", input.to_str().unwrap(), "--output", output_path.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Processed 2 rows (1 with errors)"));
+
+    let results = std::fs::read_to_string(&output_path).unwrap();
+    let lines: Vec<&str> = results.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].starts_with("1,70,1.75,30,M,90,100,22.86,Normal weight,"));
+    assert!(lines[2].ends_with("height must be greater than zero"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn cli_batch_rejects_json_format() {
+    let output = run(&["batch", "--input", "-", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--format json is not supported"));
+}
+
+#[test]
+fn cli_portuguese_usage_error() {
+    let output = run(&["bmi", "--weight", "70", "--colour", "red", "--lang", "pt-BR"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("error: opção desconhecida --colour"));
+}
+
+#[test]
+fn cli_portuguese_report() {
+    let output = run(&["bmi", "--weight", "70,0", "--height", "1,75", "--lang", "pt-BR"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("IMC: 22,86 kg/m²"));
+    assert!(stdout.contains("Classificação: Peso normal"));
+}
+
+#[test]
+fn cli_language_from_environment() {
+    let output = Command::new(env!("CARGO_BIN_EXE_fourv_saude"))
+        .env_remove("LC_ALL")
+        .env_remove("LC_MESSAGES")
+        .env("LANG", "pt_BR.UTF-8")
+        .args(["bmi", "--weight", "70", "--height", "0"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("altura deve ser maior que zero"));
+}
+
+#[test]
+fn cli_accepts_imperial_measurements() {
+    let imperial = run(&["bmi", "--weight", "160lb", "--height", "5'10\""]);
+    assert_eq!(imperial.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&imperial.stdout).starts_with("BMI: 22.96"));
+}
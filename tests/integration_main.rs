@@ -57,7 +57,7 @@ fn integration_body_fat_classification() {
     let pgc = BodyFatCalculator::calculate_pgc(bmi, data.age, &data.gender);
 
     let sex_category = BodyFatCalculator::classify_by_sex(pgc, &data.gender);
-    let age_category = BodyFatCalculator::classify_by_age(pgc, data.age, &data.gender);
+    let age_category = BodyFatCalculator::classify_by_age(pgc, data.age, &data.gender).unwrap();
 
     // Apenas verifica se as funções retornam algum valor válido
     use fourv_saude::body_fat::{BodyFatSexCategory, BodyFatAgeCategory};